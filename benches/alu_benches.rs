@@ -0,0 +1,69 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use aoc21::input::get_cached_input;
+use aoc21::years::y2021::days::twentyfour::{ArithmeticLogicUnit, CompiledProgram};
+
+// Compares the day 24 interpreter with the compiled bytecode, running MONAD
+// over a million model numbers at a time. Run with
+// `cargo bench --bench alu_benches`. MONAD comes from the cached puzzle
+// input, and the bench is skipped without one.
+
+const MODEL_NUMBERS: usize = 1_000_000;
+
+// The nth model number counting down from all 9s, skipping zeros.
+fn model_number(mut n: usize, digits: &mut [isize; 14]) {
+  for digit in digits.iter_mut().rev() {
+    *digit = 9 - (n % 9) as isize;
+    n /= 9;
+  }
+}
+
+pub fn interpreter_against_compiler(c: &mut Criterion) {
+  let program = match get_cached_input(2021, 24) {
+    Ok(Some(input)) => input,
+    Ok(None) => {
+      eprintln!("Skipping 2021 day 24: no input in inputs/2021/");
+      return;
+    },
+    Err(error) => {
+      eprintln!("Skipping 2021 day 24: {}", error);
+      return;
+    }
+  };
+
+  let mut group = c.benchmark_group("2021-day24");
+  group.sample_size(10);
+  group.throughput(Throughput::Elements(MODEL_NUMBERS as u64));
+
+  group.bench_with_input(BenchmarkId::new("monad", "interpreted"), &program, |b, program| {
+    let mut alu = ArithmeticLogicUnit::new(program, Vec::new()).unwrap();
+    let mut digits = [0; 14];
+    b.iter(|| {
+      let mut valid = 0;
+      for n in 0..MODEL_NUMBERS {
+        model_number(n, &mut digits);
+        alu.reset(&digits);
+        alu.run().unwrap();
+        valid += (alu.registers()[3] == 0) as usize;
+      }
+      black_box(valid)
+    })
+  });
+
+  group.bench_with_input(BenchmarkId::new("monad", "compiled"), &program, |b, program| {
+    let compiled = CompiledProgram::compile(program).unwrap();
+    let mut digits = [0; 14];
+    b.iter(|| {
+      let mut valid = 0;
+      for n in 0..MODEL_NUMBERS {
+        model_number(n, &mut digits);
+        valid += (compiled.run(black_box(&digits)).unwrap()[3] == 0) as usize;
+      }
+      black_box(valid)
+    })
+  });
+
+  group.finish();
+}
+
+criterion_group!(benches, interpreter_against_compiler);
+criterion_main!(benches);
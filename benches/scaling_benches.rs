@@ -0,0 +1,206 @@
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+use criterion::{
+  black_box, criterion_group, criterion_main, AxisScale, BenchmarkId, Criterion,
+  PlotConfiguration
+};
+use aoc21::input::{generate_digit_grid, get_cached_input};
+use aoc21::years::y2021::days::{six, nine, eleven, fourteen, sixteen};
+
+// Runs solutions across a sweep of problem sizes instead of a single input,
+// and fits the timings against common complexity classes. Run with
+// `cargo bench --bench scaling_benches`, or `-- day14` for a single day.
+
+const LANTERNFISH_EXAMPLE: &str = "3,4,3,1,2";
+
+const POLYMER_EXAMPLE: &str = "NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C";
+
+const GRID_SEED: u64 = 2021;
+
+// Criterion's default, set explicitly since the fit relies on knowing it.
+const SAMPLE_SIZE: usize = 100;
+
+type Model = (&'static str, fn(f64) -> f64);
+
+// Candidate complexity classes, as (name, f(n)).
+const MODELS: [Model; 6] = [
+  ("O(1)", |_| 1.),
+  ("O(log n)", |n| n.ln()),
+  ("O(n)", |n| n),
+  ("O(n log n)", |n| n * n.ln()),
+  ("O(n^2)", |n| n * n),
+  ("O(n^3)", |n| n * n * n)
+];
+
+// Iterations and time for every batch criterion runs at each size. Warm-up
+// batches come through the same closure first, so only the last
+// SAMPLE_SIZE batches are the samples the report sees, and only those go
+// into the fit.
+type Batch = (u64, Duration);
+
+struct Samples {
+  timings: RefCell<Vec<(usize, Vec<Batch>)>>
+}
+
+impl Samples {
+  fn new() -> Self {
+    Samples { timings: RefCell::new(Vec::new()) }
+  }
+
+  fn record(&self, size: usize, iters: u64, elapsed: Duration) {
+    let mut timings = self.timings.borrow_mut();
+    if let Some((_, batches)) = timings.iter_mut().find(|(s, _)| *s == size) {
+      batches.push((iters, elapsed));
+    } else {
+      timings.push((size, vec![(iters, elapsed)]));
+    }
+  }
+
+  fn means(&self) -> Vec<(f64, f64)> {
+    self.timings.borrow()
+      .iter()
+      .map(|(size, batches)| {
+        let measured = &batches[batches.len().saturating_sub(SAMPLE_SIZE)..];
+        let iters: u64 = measured.iter().map(|(iters, _)| iters).sum();
+        let elapsed: Duration = measured.iter().map(|(_, elapsed)| *elapsed).sum();
+        (*size as f64, elapsed.as_secs_f64() / iters as f64)
+      })
+      .collect()
+  }
+}
+
+// Fits t = c * f(n) for each model by least squares on relative error, and
+// the exponent k of t = c * n^k by regression on log-log data.
+fn report_fit(name: &str, samples: &Samples) {
+  let points = samples.means();
+  if points.len() < 2 { return; }
+
+  let mut fits: Vec<(&str, f64)> = MODELS
+    .iter()
+    .map(|(model, f)| {
+      let c = points.iter().map(|(n, t)| f(*n) / t).sum::<f64>() /
+              points.iter().map(|(n, t)| (f(*n) / t).powi(2)).sum::<f64>();
+      let error = points.iter().map(|(n, t)| (c * f(*n) / t - 1.).powi(2)).sum::<f64>();
+      (*model, (error / points.len() as f64).sqrt())
+    })
+    .collect();
+  fits.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+
+  let count = points.len() as f64;
+  let mean_x = points.iter().map(|(n, _)| n.ln()).sum::<f64>() / count;
+  let mean_y = points.iter().map(|(_, t)| t.ln()).sum::<f64>() / count;
+  let exponent = points.iter().map(|(n, t)| (n.ln() - mean_x) * (t.ln() - mean_y)).sum::<f64>() /
+                 points.iter().map(|(n, _)| (n.ln() - mean_x).powi(2)).sum::<f64>();
+
+  println!("\n{}: best fit {} (rms error {:.1}%), empirical O(n^{:.2})",
+           name, fits[0].0, fits[0].1 * 100., exponent);
+  for (model, error) in fits.iter().skip(1).take(2) {
+    println!("  {:12} rms error {:.1}%", model, error * 100.);
+  }
+  println!();
+}
+
+fn sweep<F, T>(c: &mut Criterion, name: &str, parameter: &str, sizes: &[usize], mut f: F)
+  where F: FnMut(usize) -> T
+{
+  let samples = Samples::new();
+  let mut group = c.benchmark_group(name);
+  group.sample_size(SAMPLE_SIZE);
+  group.plot_config(PlotConfiguration::default().summary_scale(AxisScale::Logarithmic));
+
+  for &size in sizes {
+    group.bench_with_input(BenchmarkId::new(parameter, size), &size, |b, &size| {
+      b.iter_custom(|iters| {
+        let start = Instant::now();
+        for _ in 0..iters {
+          black_box(f(black_box(size)));
+        }
+        let elapsed = start.elapsed();
+        samples.record(size, iters, elapsed);
+        elapsed
+      })
+    });
+  }
+
+  group.finish();
+  report_fit(name, &samples);
+}
+
+fn example_or_input(day: u32, example: &str) -> String {
+  match get_cached_input(2021, day) {
+    Ok(Some(input)) => input,
+    _ => example.to_string()
+  }
+}
+
+pub fn day_six(c: &mut Criterion) {
+  let input = example_or_input(6, LANTERNFISH_EXAMPLE);
+  // Populations overflow usize not long after 400 days.
+  sweep(c, "2021-day6", "days", &[25, 50, 100, 200, 400], |days| {
+    six::population_after(&input, days)
+  });
+}
+
+pub fn day_nine(c: &mut Criterion) {
+  let grids: Vec<(usize, String)> = [25, 50, 100, 200, 400]
+    .iter()
+    .map(|&size| (size, generate_digit_grid(size, GRID_SEED)))
+    .collect();
+  let sizes: Vec<usize> = grids.iter().map(|(size, _)| *size).collect();
+  let grid = |size| &grids.iter().find(|(s, _)| *s == size).unwrap().1;
+
+  sweep(c, "2021-day9-part_one", "grid_size", &sizes, |size| nine::part_one(grid(size)));
+  sweep(c, "2021-day9-part_two", "grid_size", &sizes, |size| nine::part_two(grid(size)));
+}
+
+pub fn day_eleven(c: &mut Criterion) {
+  let grids: Vec<(usize, String)> = [10, 20, 40, 80, 160]
+    .iter()
+    .map(|&size| (size, generate_digit_grid(size, GRID_SEED)))
+    .collect();
+  let sizes: Vec<usize> = grids.iter().map(|(size, _)| *size).collect();
+  let grid = |size| &grids.iter().find(|(s, _)| *s == size).unwrap().1;
+
+  // Bigger grids rarely synchronize, so only part one's fixed step count is
+  // swept.
+  sweep(c, "2021-day11", "grid_size", &sizes, |size| eleven::flashes_after(grid(size), 100));
+}
+
+pub fn day_fourteen(c: &mut Criterion) {
+  let input = example_or_input(14, POLYMER_EXAMPLE);
+  // Element counts overflow usize somewhere past 50 iterations.
+  sweep(c, "2021-day14", "iterations", &[5, 10, 20, 40, 50], |iterations| {
+    fourteen::element_spread(&input, iterations)
+  });
+}
+
+pub fn day_sixteen(c: &mut Criterion) {
+  let transmissions: Vec<(usize, String)> = [100, 1_000, 10_000, 100_000]
+    .iter()
+    .map(|&size| (size, sixteen::synthetic_transmission(size, GRID_SEED).unwrap()))
+    .collect();
+  let sizes: Vec<usize> = transmissions.iter().map(|(size, _)| *size).collect();
+  let transmission = |size| &transmissions.iter().find(|(s, _)| *s == size).unwrap().1;
+
+  sweep(c, "2021-day16", "packets", &sizes, |size| sixteen::part_two(transmission(size)));
+}
+
+criterion_group!(benches, day_six, day_nine, day_eleven, day_fourteen, day_sixteen);
+criterion_main!(benches);
@@ -0,0 +1,44 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use aoc21::input::get_cached_input;
+use aoc21::years::y2021::days::eighteen::{
+  homework_magnitude, largest_pair_magnitude, FlatNumber, Number
+};
+
+// Compares the tree and flat snailfish number representations on the same
+// homework list. Run with `cargo bench --bench snailfish_benches`.
+
+const HOMEWORK_EXAMPLE: &str = "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]";
+
+pub fn representations(c: &mut Criterion) {
+  let input = match get_cached_input(2021, 18) {
+    Ok(Some(input)) => input,
+    _ => HOMEWORK_EXAMPLE.to_string()
+  };
+
+  let mut group = c.benchmark_group("2021-day18");
+  group.bench_with_input(BenchmarkId::new("part_one", "tree"), &input, |b, input| {
+    b.iter(|| homework_magnitude::<Number>(black_box(input)))
+  });
+  group.bench_with_input(BenchmarkId::new("part_one", "flat"), &input, |b, input| {
+    b.iter(|| homework_magnitude::<FlatNumber>(black_box(input)))
+  });
+  group.bench_with_input(BenchmarkId::new("part_two", "tree"), &input, |b, input| {
+    b.iter(|| largest_pair_magnitude::<Number>(black_box(input)))
+  });
+  group.bench_with_input(BenchmarkId::new("part_two", "flat"), &input, |b, input| {
+    b.iter(|| largest_pair_magnitude::<FlatNumber>(black_box(input)))
+  });
+  group.finish();
+}
+
+criterion_group!(benches, representations);
+criterion_main!(benches);
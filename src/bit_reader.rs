@@ -0,0 +1,130 @@
+use crate::aoc_error::AocError;
+
+// Reads big-endian bit fields of any width up to 64 bits from a buffer of
+// bytes, keeping track of how far in it is so errors can say where they
+// happened.
+#[derive(Debug)]
+pub struct BitReader {
+    bytes: Vec<u8>,
+    len: usize,
+    position: usize
+}
+
+impl BitReader {
+    pub fn new(bytes: Vec<u8>) -> Self {
+        let len = bytes.len() * 8;
+        BitReader { bytes, len, position: 0 }
+    }
+
+    // Packs a string of hex digits two to a byte. An odd digit out takes up
+    // the top half of the last byte, and the bottom half isn't readable.
+    pub fn from_hex(hex: &str) -> Result<Self, AocError> {
+        let mut bytes = Vec::with_capacity(hex.len().div_ceil(2));
+        let mut digits = 0;
+        for (i, c) in hex.char_indices() {
+            let digit = c.to_digit(16)
+                .ok_or_else(|| AocError::Misc(format!("Bad hex digit '{}' at character {}", c, i)))? as u8;
+            if digits % 2 == 0 {
+                bytes.push(digit << 4);
+            } else {
+                *bytes.last_mut().unwrap() |= digit;
+            }
+            digits += 1;
+        }
+
+        Ok(BitReader { bytes, len: digits * 4, position: 0 })
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.len - self.position
+    }
+
+    pub fn read(&mut self, width: usize) -> Result<u64, AocError> {
+        if width > 64 {
+            return Err(AocError::Misc(format!("Can't read {} bits into a u64", width)));
+        }
+        if width > self.remaining() {
+            return Err(AocError::Misc(format!(
+                "Ran out of bits reading a {} bit field at bit {} of {}",
+                width, self.position, self.len
+            )));
+        }
+
+        // Takes as much of the current byte as it can at a time, so each byte
+        // the field touches is only looked at once.
+        let mut value: u64 = 0;
+        let mut wanted = width;
+        while wanted > 0 {
+            let byte = self.bytes[self.position / 8];
+            let offset = self.position % 8;
+            let take = wanted.min(8 - offset);
+            let bits = (byte >> (8 - offset - take)) & (0xFF >> (8 - take));
+
+            value = (value << take) | bits as u64;
+            self.position += take;
+            wanted -= take;
+        }
+
+        Ok(value)
+    }
+
+    pub fn read_bit(&mut self) -> Result<bool, AocError> {
+        Ok(self.read(1)? == 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BitReader;
+
+    #[test]
+    fn reads_fields_across_bytes() {
+        let mut reader = BitReader::from_hex("D2FE28").unwrap();
+        assert_eq!(reader.len(), 24);
+        assert_eq!(reader.read(3).unwrap(), 6);
+        assert_eq!(reader.read(3).unwrap(), 4);
+        assert_eq!(reader.read(15).unwrap(), 0b101111111000101);
+        assert_eq!(reader.position(), 21);
+        assert_eq!(reader.remaining(), 3);
+    }
+
+    #[test]
+    fn reads_wide_fields() {
+        let mut reader = BitReader::from_hex("0123456789ABCDEF0F").unwrap();
+        assert_eq!(reader.read(4).unwrap(), 0);
+        assert_eq!(reader.read(64).unwrap(), 0x123456789ABCDEF0);
+        assert_eq!(reader.read(4).unwrap(), 0xF);
+        assert!(reader.read(65).is_err());
+    }
+
+    #[test]
+    fn handles_odd_digit_counts() {
+        let mut reader = BitReader::from_hex("ABC").unwrap();
+        assert_eq!(reader.len(), 12);
+        assert_eq!(reader.read(12).unwrap(), 0xABC);
+        assert!(reader.read(1).is_err());
+    }
+
+    #[test]
+    fn reports_where_errors_happened() {
+        let error = BitReader::from_hex("12x4").unwrap_err().to_string();
+        assert!(error.contains("'x' at character 2"), "{}", error);
+
+        let mut reader = BitReader::from_hex("FF").unwrap();
+        reader.read(5).unwrap();
+        let error = reader.read(4).unwrap_err().to_string();
+        assert!(error.contains("at bit 5 of 8"), "{}", error);
+    }
+}
@@ -0,0 +1,125 @@
+use std::io::Read;
+use std::path::PathBuf;
+
+use reqwest;
+use chrono::prelude::{Utc, TimeZone};
+
+use crate::aoc_error::AocError;
+
+fn input_path(year: u32, day: u32) -> PathBuf {
+    PathBuf::from(format!("inputs/{}/{}.txt", year, day))
+}
+
+// Before there was more than one year, 2021's inputs were cached straight
+// under inputs/. Moves one of those into its year's directory if it's there,
+// so it doesn't get downloaded again.
+fn move_legacy_input(year: u32, day: u32) -> Result<(), AocError> {
+    let legacy = PathBuf::from(format!("inputs/{}.txt", day));
+    if year != 2021 || !legacy.exists() {
+        return Ok(());
+    }
+
+    let path = input_path(year, day);
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::rename(legacy, path)?;
+    Ok(())
+}
+
+fn download_input(year: u32, day: u32) -> Result<String, AocError> {
+    let now = Utc::now();
+    // AoC is always run so that puzzles unlock at midnight EST, UTC-5.
+    // Therefore, if it's before 5 AM UTC on Dec N, the puzzle is
+    // unavailable, and there's no point downloading it.
+    if now < Utc.ymd(year as i32, 12, day).and_hms(5, 0, 0) {
+        return Err(AocError::TooEarly);
+    }
+
+    let mut session_cookie = String::new();
+    let mut cookie_file = std::fs::File::open(".advent-session-cookie")?;
+    cookie_file.read_to_string(&mut session_cookie)?;
+
+    let client = reqwest::blocking::Client::new();
+    let res = client
+        .get(format!("https://adventofcode.com/{}/day/{}/input", year, day))
+        .header("Cookie", format!("session={}", session_cookie.trim()))
+        .send()?;
+
+    let input = res.text()?;
+    if input.starts_with("Please don't repeatedly request this endpoint before it unlocks") {
+        // Whoops, my date math was wrong.
+        return Err(AocError::TooEarly);
+    }
+
+    Ok(input)
+}
+
+// Reads a previously downloaded input, without going to the network for it.
+// Returns None if we don't have the input yet.
+pub fn get_cached_input(year: u32, day: u32) -> Result<Option<String>, AocError> {
+    let path = input_path(year, day);
+    if !path.exists() {
+        move_legacy_input(year, day)?;
+        if !path.exists() {
+            return Ok(None);
+        }
+    }
+
+    let mut input_file = std::fs::File::open(path)?;
+    let mut buffer = String::new();
+    input_file.read_to_string(&mut buffer)?;
+    Ok(Some(buffer.trim().to_string()))
+}
+
+// Fetches the puzzle input for a day, downloading and caching it under
+// inputs/<year>/ the first time it's asked for.
+pub fn get_input(year: u32, day: u32) -> Result<String, AocError> {
+    if let Some(input) = get_cached_input(year, day)? {
+        return Ok(input);
+    }
+
+    let input = download_input(year, day)?;
+    let path = input_path(year, day);
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(&path, &input)?;
+
+    Ok(input.trim().to_string())
+}
+
+// A small linear congruential generator for building synthetic inputs. Not
+// remotely random enough for anything else, but the same seed always makes
+// the same input.
+#[derive(Debug)]
+pub struct Lcg(u64);
+
+impl Lcg {
+    pub fn new(seed: u64) -> Self {
+        Lcg(seed)
+    }
+
+    // A number in 0..n
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (self.0 >> 33) % n
+    }
+}
+
+// Builds a synthetic square grid of digits, for scaling solutions that take
+// grid-shaped input past the size of the real puzzle.
+pub fn generate_digit_grid(size: usize, seed: u64) -> String {
+    let mut rng = Lcg::new(seed);
+    let mut grid = String::with_capacity(size * (size + 1));
+
+    for row in 0..size {
+        if row > 0 { grid.push('\n'); }
+        for _ in 0..size {
+            let digit = rng.below(10) as u32;
+            grid.push(std::char::from_digit(digit, 10).unwrap());
+        }
+    }
+
+    grid
+}
@@ -1,2 +1,4 @@
 pub mod aoc_error;
-pub mod days;
+pub mod bit_reader;
+pub mod input;
+pub mod years;
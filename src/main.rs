@@ -1,16 +1,11 @@
-mod aoc_error;
-mod days;
-
-use std::io::Read;
 use std::env;
 use std::fmt::Display;
+use std::io::{self, BufRead};
 use std::time::{Instant, Duration};
 
-use reqwest;
-use chrono::prelude::{Utc, TimeZone};
-
-use crate::aoc_error::AocError;
-use crate::days::{get_day, Day};
+use aoc21::aoc_error::AocError;
+use aoc21::input::{get_cached_input, get_input};
+use aoc21::years::{get_day, get_year, Day, YEARS};
 
 fn format_result<V, E>(result: &Result<V, E>) -> String
     where V: Display,
@@ -42,47 +37,9 @@ struct DayReport {
     part_two_duration: Duration
 }
 
-fn get_input(day: &str) -> Result<String, AocError> {
-    let fname = format!("inputs/{}.txt", day);
-    let path = std::path::Path::new(&fname);
-
-    if !path.exists() {
-        let now = Utc::now();
-        // AoC is always run so that puzzles unlock at midnight EST, UTC-5.
-        // Therefore, if it's before 5 AM UTC on Dec N 2021, the puzzle is
-        // unavailable, and there's no point downloading it.
-        if now < Utc.ymd(2021, 12, day.parse()?).and_hms(5, 0, 0) {
-            return Err(AocError::TooEarly);
-        }
-
-        let mut session_cookie = String::new();
-        let mut cookie_file = std::fs::File::open(".advent-session-cookie")?;
-        cookie_file.read_to_string(&mut session_cookie)?;
-
-        let client = reqwest::blocking::Client::new();
-        let res = client
-            .get(format!("https://adventofcode.com/2021/day/{}/input", day))
-            .header("Cookie", format!("session={}", session_cookie.trim()))
-            .send()?;
-
-        let input = res.text()?;
-        if input.starts_with("Please don't repeatedly request this endpoint before it unlocks") {
-            // Whoops, my date math was wrong.
-            return Err(AocError::TooEarly);
-        }
-
-        std::fs::write(path, input)?;
-    }
-
-    let mut input_file = std::fs::File::open(path)?;
-    let mut buffer = String::new();
-    input_file.read_to_string(&mut buffer)?;
-    Ok(buffer.trim().to_string())
-}
-
 fn run(day: &Day) -> Result<DayReport, AocError> {
     let start = Instant::now();
-    let input = get_input(&day.day)?;
+    let input = get_input(day.year, day.day)?;
     let file_duration = start.elapsed();
 
     let start = Instant::now();
@@ -102,23 +59,138 @@ fn run(day: &Day) -> Result<DayReport, AocError> {
     })
 }
 
+struct Args {
+    year: u32,
+    day: Option<u32>,
+    view: Option<String>,
+    view_args: Vec<String>,
+    step: bool
+}
+
+impl Args {
+    fn parse<I>(args: I) -> Result<Self, String>
+        where I: Iterator<Item = String>
+    {
+        // Default to the most recent year we have solutions for.
+        let mut year = YEARS[YEARS.len() - 1];
+        let mut day = None;
+        let mut view = None;
+        let mut view_args = Vec::new();
+        let mut step = false;
+
+        let mut args = args;
+        while let Some(arg) = args.next() {
+            if arg == "--year" {
+                let value = args.next().ok_or_else(|| "Missing value for --year".to_string())?;
+                year = value.parse().map_err(|_| format!("Bad year \"{}\"", value))?;
+            } else if arg == "--view" {
+                view = Some(args.next().ok_or_else(|| "Missing value for --view".to_string())?);
+            } else if arg == "--step" {
+                step = true;
+            } else if day.is_none() {
+                day = Some(arg.parse().map_err(|_| format!("Bad day \"{}\"", arg))?);
+            } else if view.is_some() {
+                // Anything after the day is passed along to the view.
+                view_args.push(arg);
+            } else {
+                return Err(format!("Unexpected argument \"{}\"", arg));
+            }
+        }
+
+        if view.is_some() && day.is_none() {
+            return Err("--view needs a day".to_string());
+        }
+
+        Ok(Args { year, day, view, view_args, step })
+    }
+}
+
+fn list_views(day: &Day) {
+    if day.views.is_empty() {
+        println!("{} day {} has no views.", day.year, day.day);
+    } else {
+        println!("Views for {} day {}:", day.year, day.day);
+        for view in day.views {
+            println!("  {:12} {}", view.name, view.description);
+        }
+    }
+}
+
+// Prints each frame of a view, pausing for enter between them when stepping.
+fn show_view(day: &Day, name: &str, args: &[String], step: bool) -> Result<(), AocError> {
+    let view = match day.view(name) {
+        Some(view) => view,
+        None => {
+            println!("No view \"{}\".", name);
+            list_views(day);
+            return Ok(());
+        }
+    };
+
+    // Views given their own input on the command line shouldn't need the
+    // puzzle input downloaded.
+    let input = if view.args_replace_input && !args.is_empty() {
+        get_cached_input(day.year, day.day)?.unwrap_or_default()
+    } else {
+        get_input(day.year, day.day)?
+    };
+    let frames = (view.render)(&input, args)?;
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    for (i, frame) in frames.iter().enumerate() {
+        if i > 0 {
+            if step {
+                if lines.next().is_none() { break; }
+            } else {
+                println!();
+            }
+        }
+        println!("{}", frame);
+    }
+
+    Ok(())
+}
+
 fn main() {
     let start = Instant::now();
 
-    let args: Vec<String> = env::args().collect();
+    let args = match Args::parse(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => {
+            println!("{}", message);
+            println!("Usage: aoc21 [--year YYYY] [DAY]");
+            println!("       aoc21 [--year YYYY] --view NAME [--step] DAY [ARGS...]");
+            return;
+        }
+    };
+
+    if let (Some(view), Some(day)) = (&args.view, args.day) {
+        match get_day(args.year, day) {
+            Some(day) => {
+                if let Err(error) = show_view(&day, view, &args.view_args, args.step) {
+                    println!("{}", error);
+                }
+            },
+            None => println!("No implementation for {} day {}.", args.year, day)
+        }
+        return;
+    }
 
     let mut days: Vec<Day> = Vec::new();
-    if args.len() < 2 {
-        for i in 1..26 {
-            if let Some(day) = get_day(&i.to_string()) {
-                days.push(day);
-            }
+    if let Some(day) = args.day {
+        if let Some(day) = get_day(args.year, day) {
+            days.push(day);
+        } else {
+            println!("No implementation for {} day {}.", args.year, day);
+            return;
         }
-    } else if let Some(day) = get_day(&args[1]) {
-        days.push(day);
     } else {
-        println!("No implementation for day {}.", &args[1]);
-        return;
+        days = get_year(args.year);
+        if days.is_empty() {
+            println!("No implementations for {}.", args.year);
+            return;
+        }
     }
 
     let mut total_problem_duration = Duration::new(0, 0);
@@ -127,7 +199,7 @@ fn main() {
     for day in days {
         let report = run(&day);
 
-        println!("\nDay {}: {}", day.day, day.name);
+        println!("\n{} Day {}: {}", day.year, day.day, day.name);
         match report {
             Err(error) => println!("  {}", error),
             Ok(report) => {
@@ -0,0 +1,85 @@
+use crate::aoc_error::AocError;
+
+pub type Solution = fn(&str) -> Result<String, AocError>;
+// Just the input parsing step of a solution, so it can be timed on its own.
+pub type Parser = fn(&str) -> Result<(), AocError>;
+// Renders a debugging view of a solution, given the puzzle input and any
+// extra arguments from the command line. Views that play out over several
+// steps return one frame per step.
+pub type Render = fn(&str, &[String]) -> Result<Vec<String>, AocError>;
+
+pub struct View {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub render: Render,
+    // Whether arguments stand in for the puzzle input, so it isn't needed
+    // when there are any, rather than just tweaking what's shown of it.
+    pub args_replace_input: bool
+}
+
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    pub name: String,
+    pub parse: Option<Parser>,
+    pub views: &'static [View],
+    pub part_one: Solution,
+    pub part_two: Solution
+}
+
+impl Day {
+    pub fn view(&self, name: &str) -> Option<&View> {
+        self.views.iter().find(|view| view.name == name)
+    }
+}
+
+// Each year's module uses this to build its own registry, mapping day numbers
+// to that year's solution modules. Days whose module has extras beyond its
+// answers are marked with them: `+ parse` for a separate `parse` function and
+// `+ views` for a `VIEWS` list.
+macro_rules! match_day {
+    ( $year:literal $target:ident $( $day:literal => $module:ident $( + $extra:ident )* ),* ) => {
+        match $target {
+            $(
+                $day => {
+                    #[allow(unused_mut)]
+                    let mut day = crate::years::Day {
+                        year: $year,
+                        day: $day,
+                        name: days::$module::NAME.to_string(),
+                        parse: None,
+                        views: &[],
+                        part_one: days::$module::part_one,
+                        part_two: days::$module::part_two
+                    };
+                    $( match_day!(@extra day $module $extra); )*
+                    Some(day)
+                },
+            )*
+            _ => None
+        }
+    };
+    ( @extra $day:ident $module:ident parse ) => {
+        $day.parse = Some(days::$module::parse as crate::years::Parser);
+    };
+    ( @extra $day:ident $module:ident views ) => {
+        $day.views = days::$module::VIEWS;
+    };
+}
+
+pub mod y2021;
+
+pub const YEARS: [u32; 1] = [2021];
+
+pub fn get_day(year: u32, day: u32) -> Option<Day> {
+    match year {
+        2021 => y2021::get_day(day),
+        _ => None
+    }
+}
+
+pub fn get_year(year: u32) -> Vec<Day> {
+    (1..=25)
+        .filter_map(|day| get_day(year, day))
+        .collect()
+}
@@ -29,11 +29,7 @@ impl FromStr for Segments {
                 'g' => Ok(G),
                 _ => Err(AocError::Misc(format!("Invalid segment \"{}\"", c)))
             })
-            .fold(Ok(0), |acc, x| match (acc, x) {
-                (Ok(acc), Ok(x)) => Ok(acc | x),
-                (Err(e), _) => Err(e),
-                (_, Err(e)) => Err(e)
-            })?;
+            .try_fold(0, |acc, x| x.map(|x| acc | x))?;
         Ok(Segments(value))
     }
 }
@@ -57,35 +53,42 @@ struct SegmentDictionary {
     digits: Vec<Segments>
 }
 
+// Segment counts of the ten digits, in order of length.
+const DIGIT_LENGTHS: [u32; 10] = [2, 3, 4, 5, 5, 5, 6, 6, 6, 7];
+
 impl FromStr for SegmentDictionary {
     type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(SegmentDictionary {
-            digits: s.split(" ")
-                .map(|s| s.parse())
-                .collect::<Result<Vec<Segments>, AocError>>()?
-        })
+        let digits = s.split(" ")
+            .map(|s| s.parse())
+            .collect::<Result<Vec<Segments>, AocError>>()?;
+
+        let mut lengths: Vec<u32> = digits.iter().map(|d| d.len()).collect();
+        lengths.sort_unstable();
+        if lengths != DIGIT_LENGTHS {
+            return Err(AocError::Misc(format!("Signal patterns \"{}\" aren't one of each digit", s)));
+        }
+
+        Ok(SegmentDictionary { digits })
     }
 }
 
 impl SegmentDictionary {
-    // Unwraps in digit finders are safe as long as we assume the dictionary
+    // Unwraps in digit finders are safe since parsing checks the dictionary
     // is well-formed - it has one of every digit.
 
     fn one(&self) -> &Segments {
         self.digits
             .iter()
-            .filter(|s| s.len() == 2)
-            .next()
+            .find(|s| s.len() == 2)
             .unwrap()
     }
 
     fn four(&self) -> &Segments {
         self.digits
             .iter()
-            .filter(|s| s.len() == 4)
-            .next()
+            .find(|s| s.len() == 4)
             .unwrap()
     }
 
@@ -131,12 +134,14 @@ impl FromStr for KrangledDisplay {
         let (signals, outputs) = s.split_once(" | ")
             .ok_or_else(|| AocError::Misc(format!("Invalid display string \"{}\"", s)))?;
 
-        Ok(KrangledDisplay {
-            signals: signals.parse()?,
-            outputs: outputs.split(" ")
-                .map(|s| s.parse())
-                .collect::<Result<Vec<Segments>, AocError>>()?
-        })
+        let outputs = outputs.split(" ")
+            .map(|s| s.parse())
+            .collect::<Result<Vec<Segments>, AocError>>()?;
+        if outputs.len() != 4 {
+            return Err(AocError::Misc(format!("Expected 4 output digits in \"{}\"", s)));
+        }
+
+        Ok(KrangledDisplay { signals: signals.parse()?, outputs })
     }
 }
 
@@ -149,25 +154,35 @@ impl KrangledDisplay {
     }
 }
 
+fn parse_displays(input: &str) -> Result<Vec<KrangledDisplay>, AocError> {
+    input.lines()
+        .map(|l| l.parse())
+        .collect()
+}
+
+pub fn parse(input: &str) -> Result<(), AocError> {
+    parse_displays(input)?;
+
+    Ok(())
+}
+
 pub fn part_one(input: &str) -> Result<String, AocError> {
-    let count = input.lines()
-        .map(|l| l.parse::<KrangledDisplay>())
-        .fold(Ok(0), |sum: Result<usize, AocError>, display| {
-            let specials = display?.outputs
-                .iter()
-                .filter(|s| s.is_1_or_4_or_7_or_8())
-                .count();
-            Ok(sum? + specials)
-        })?;
+    let count: usize = parse_displays(input)?
+        .iter()
+        .map(|display| display.outputs
+            .iter()
+            .filter(|s| s.is_1_or_4_or_7_or_8())
+            .count())
+        .sum();
 
     Ok(count.to_string())
 }
 
 pub fn part_two(input: &str) -> Result<String, AocError> {
-    let sum = input.lines()
-        .map(|l| l.parse::<KrangledDisplay>())
-        .fold(Ok(0), |sum: Result<usize, AocError>, display| {
-            Ok(sum? + display?.output()?)
+    let sum = parse_displays(input)?
+        .iter()
+        .try_fold(0, |sum: usize, display| {
+            Ok::<_, AocError>(sum + display.output()?)
         })?;
 
     Ok(sum.to_string())
@@ -0,0 +1,648 @@
+use std::fmt::{self, Display, Formatter};
+use std::iter::Sum;
+use std::ops::Add;
+use std::str::FromStr;
+use crate::aoc_error::AocError;
+use crate::years::View;
+
+pub const NAME: &str = "Snailfish";
+
+// What part one and two need from a snailfish number, so they can be run on
+// either representation.
+pub trait Snailfish: Clone + Display + FromStr<Err = AocError> + Add<Output = Self> {
+    fn reduce(&mut self);
+    fn magnitude(&self) -> usize;
+}
+
+// A snailfish number as a tree of pairs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Number {
+    Literal(u32),
+    Pair(Box<Number>, Box<Number>)
+}
+
+impl FromStr for Number {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(inner) = s.strip_prefix('[') {
+            let inner = inner.strip_suffix(']')
+                .ok_or_else(|| AocError::Misc(format!("Unclosed pair \"{}\"", s)))?;
+
+            let mut depth = 0;
+            for (i, c) in inner.char_indices() {
+                if c == '[' {
+                    depth += 1;
+                } else if c == ']' {
+                    depth -= 1;
+                } else if c == ',' && depth == 0 {
+                    let left = inner[..i].parse()?;
+                    let right = inner[i+1..].parse()?;
+                    return Ok(Number::Pair(Box::new(left), Box::new(right)))
+                }
+            }
+            Err(AocError::Misc("No middle comma in pair".to_string()))
+        } else {
+            Ok(Number::Literal(
+                s.parse()?
+            ))
+        }
+    }
+}
+
+impl Display for Number {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            Number::Literal(n) => write!(f, "{}", n),
+            Number::Pair(left, right) => write!(f, "[{},{}]", left, right)
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Right
+}
+
+// What happened at one step of adding two numbers. Paths lead from the
+// outermost pair to the pair that exploded or the number that split.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    Addition,
+    Explode { path: Vec<Side>, left: u32, right: u32 },
+    Split { path: Vec<Side>, value: u32 }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub action: Action,
+    pub result: String
+}
+
+fn format_path(path: &[Side]) -> String {
+    path.iter().map(|side| match side { Side::Left => 'L', Side::Right => 'R' }).collect()
+}
+
+impl Step {
+    // The action in more detail than the puzzle's own format gives.
+    pub fn describe(&self) -> String {
+        match &self.action {
+            Action::Addition => format!("add to get {}", self.result),
+            Action::Explode { path, left, right } => {
+                format!("explode [{},{}] at {}", left, right, format_path(path))
+            },
+            Action::Split { path, value } => format!("split {} at {}", value, format_path(path))
+        }
+    }
+}
+
+// Formatted like the puzzle's worked example.
+impl Display for Step {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        let label = match self.action {
+            Action::Addition => "after addition:",
+            Action::Explode { .. } => "after explode:",
+            Action::Split { .. } => "after split:"
+        };
+        write!(f, "{:16}{}", label, self.result)
+    }
+}
+
+// An explosion on its way back out of the number. The carries are the halves
+// of the pair that still need adding to the nearest regular number on either
+// side.
+struct Explosion {
+    pair: (u32, u32),
+    left_carry: Option<u32>,
+    right_carry: Option<u32>
+}
+
+impl Number {
+    // Explodes the leftmost pair nested inside four others, leaving `path`
+    // leading to it.
+    fn explode_at(&mut self, depth: usize, path: &mut Vec<Side>) -> Option<Explosion> {
+        match self {
+            Number::Literal(_) => None,
+            Number::Pair(left, right) => {
+                if depth >= 4 {
+                    if let (Number::Literal(left), Number::Literal(right)) = (&**left, &**right) {
+                        let pair = (*left, *right);
+                        *self = Number::Literal(0);
+                        return Some(Explosion { pair, left_carry: Some(pair.0), right_carry: Some(pair.1) });
+                    }
+                }
+
+                path.push(Side::Left);
+                if let Some(mut explosion) = left.explode_at(depth + 1, path) {
+                    if let Some(value) = explosion.right_carry.take() {
+                        right.add_to_leftmost(value);
+                    }
+                    return Some(explosion);
+                }
+                path.pop();
+
+                path.push(Side::Right);
+                if let Some(mut explosion) = right.explode_at(depth + 1, path) {
+                    if let Some(value) = explosion.left_carry.take() {
+                        left.add_to_rightmost(value);
+                    }
+                    return Some(explosion);
+                }
+                path.pop();
+
+                None
+            }
+        }
+    }
+
+    fn add_to_leftmost(&mut self, value: u32) {
+        match self {
+            Number::Literal(n) => *n = n.saturating_add(value),
+            Number::Pair(left, _) => left.add_to_leftmost(value)
+        }
+    }
+
+    fn add_to_rightmost(&mut self, value: u32) {
+        match self {
+            Number::Literal(n) => *n = n.saturating_add(value),
+            Number::Pair(_, right) => right.add_to_rightmost(value)
+        }
+    }
+
+    fn explode(&mut self) -> Option<Action> {
+        let mut path = Vec::new();
+        let explosion = self.explode_at(0, &mut path)?;
+        let (left, right) = explosion.pair;
+        Some(Action::Explode { path, left, right })
+    }
+
+    // Splits the leftmost regular number that's 10 or more, leaving `path`
+    // leading to it.
+    fn split_at(&mut self, path: &mut Vec<Side>) -> Option<u32> {
+        match self {
+            Number::Literal(n) if *n >= 10 => {
+                let n = *n;
+                *self = Number::Pair(Box::new(Number::Literal(n / 2)), Box::new(Number::Literal(n.div_ceil(2))));
+                Some(n)
+            },
+            Number::Literal(_) => None,
+            Number::Pair(left, right) => {
+                for (side, number) in [(Side::Left, left), (Side::Right, right)] {
+                    path.push(side);
+                    if let Some(value) = number.split_at(path) {
+                        return Some(value);
+                    }
+                    path.pop();
+                }
+                None
+            }
+        }
+    }
+
+    fn split(&mut self) -> Option<Action> {
+        let mut path = Vec::new();
+        let value = self.split_at(&mut path)?;
+        Some(Action::Split { path, value })
+    }
+
+    // Reduces the number, recording every action taken along the way.
+    fn reduce_traced(&mut self, steps: &mut Vec<Step>) {
+        while let Some(action) = self.explode().or_else(|| self.split()) {
+            steps.push(Step { action, result: self.to_string() });
+        }
+    }
+
+    // Adds two numbers the same as `+` does, along with every step it took.
+    pub fn add_traced(self, other: Number) -> (Number, Vec<Step>) {
+        let mut sum = Number::Pair(Box::new(self), Box::new(other));
+        let mut steps = vec![Step { action: Action::Addition, result: sum.to_string() }];
+        sum.reduce_traced(&mut steps);
+        (sum, steps)
+    }
+}
+
+impl Snailfish for Number {
+    fn reduce(&mut self) {
+        while self.explode().is_some() || self.split().is_some() {}
+    }
+
+    fn magnitude(&self) -> usize {
+        match self {
+            Number::Literal(n) => *n as usize,
+            Number::Pair(left, right) => 3 * left.magnitude() + 2 * right.magnitude()
+        }
+    }
+}
+
+impl Add for Number {
+    type Output = Number;
+
+    fn add(self, other: Number) -> Number {
+        let mut sum = Number::Pair(Box::new(self), Box::new(other));
+        sum.reduce();
+        sum
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Element {
+    number: u32,
+    depth: u32
+}
+
+// A snailfish number as its regular numbers in order, each with how many
+// pairs it's nested inside.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlatNumber {
+    elements: Vec<Element>
+}
+
+impl From<&Number> for FlatNumber {
+    fn from(number: &Number) -> Self {
+        fn flatten(number: &Number, depth: u32, elements: &mut Vec<Element>) {
+            match number {
+                Number::Literal(n) => elements.push(Element { number: *n, depth }),
+                Number::Pair(left, right) => {
+                    flatten(left, depth + 1, elements);
+                    flatten(right, depth + 1, elements);
+                }
+            }
+        }
+
+        let mut elements = Vec::new();
+        flatten(number, 0, &mut elements);
+        FlatNumber { elements }
+    }
+}
+
+impl From<&FlatNumber> for Number {
+    fn from(number: &FlatNumber) -> Self {
+        let mut stack: Vec<(u32, Number)> = Vec::new();
+        for element in number.elements.iter() {
+            stack.push((element.depth, Number::Literal(element.number)));
+            while let [.., (left_depth, _), (right_depth, _)] = stack[..] {
+                if left_depth != right_depth { break; }
+                let (_, right) = stack.pop().unwrap();
+                let (_, left) = stack.pop().unwrap();
+                stack.push((left_depth.saturating_sub(1), Number::Pair(Box::new(left), Box::new(right))));
+            }
+        }
+
+        stack.pop().map(|(_, number)| number).unwrap_or(Number::Literal(0))
+    }
+}
+
+impl FromStr for FlatNumber {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(FlatNumber::from(&s.parse::<Number>()?))
+    }
+}
+
+impl Display for FlatNumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "{}", Number::from(self))
+    }
+}
+
+impl FlatNumber {
+    // The index of the left half of the leftmost pair of regular numbers
+    // nested inside four others. Neighbours at the same depth aren't always a
+    // pair, but the first ones this deep are: anything to the left of a right
+    // half would be at least as deep, and would have been found first.
+    fn find_exploder(&self) -> Option<usize> {
+        self.elements
+            .windows(2)
+            .position(|pair| pair[0].depth > 4 && pair[0].depth == pair[1].depth)
+    }
+
+    fn explode(&mut self) -> bool {
+        if let Some(i) = self.find_exploder() {
+            let left = self.elements[i];
+            let right = self.elements.remove(i + 1);
+
+            if i > 0 {
+                let neighbour = &mut self.elements[i - 1].number;
+                *neighbour = neighbour.saturating_add(left.number);
+            }
+            if let Some(neighbour) = self.elements.get_mut(i + 1) {
+                neighbour.number = neighbour.number.saturating_add(right.number);
+            }
+            self.elements[i] = Element { number: 0, depth: left.depth - 1 };
+
+            true
+        } else {
+            false
+        }
+    }
+
+    fn split(&mut self) -> bool {
+        if let Some(i) = self.elements.iter().position(|element| element.number >= 10) {
+            let Element { number, depth } = self.elements[i];
+            self.elements[i] = Element { number: number / 2, depth: depth + 1 };
+            self.elements.insert(i + 1, Element { number: number.div_ceil(2), depth: depth + 1 });
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl Snailfish for FlatNumber {
+    fn reduce(&mut self) {
+        while self.explode() || self.split() {}
+    }
+
+    fn magnitude(&self) -> usize {
+        let mut stack: Vec<(u32, usize)> = Vec::new();
+        for element in self.elements.iter() {
+            stack.push((element.depth, element.number as usize));
+            while let [.., (left_depth, left), (right_depth, right)] = stack[..] {
+                if left_depth != right_depth { break; }
+                stack.truncate(stack.len() - 2);
+                stack.push((left_depth.saturating_sub(1), 3 * left + 2 * right));
+            }
+        }
+
+        stack.pop().map(|(_, magnitude)| magnitude).unwrap_or(0)
+    }
+}
+
+impl Add for FlatNumber {
+    type Output = FlatNumber;
+
+    fn add(self, other: FlatNumber) -> FlatNumber {
+        let mut elements = self.elements;
+        elements.extend(other.elements);
+        for element in elements.iter_mut() {
+            element.depth += 1;
+        }
+
+        let mut sum = FlatNumber { elements };
+        sum.reduce();
+        sum
+    }
+}
+
+// There's no snailfish zero to start from, so the sum of no numbers is None.
+// The sum of one number is that number reduced, same as any other sum.
+fn total<N, I>(mut iter: I) -> Option<N>
+    where N: Snailfish,
+          I: Iterator<Item = N>
+{
+    let mut first = iter.next()?;
+    first.reduce();
+    Some(iter.fold(first, |sum, number| sum + number))
+}
+
+impl Sum<Number> for Option<Number> {
+    fn sum<I: Iterator<Item = Number>>(iter: I) -> Self {
+        total(iter)
+    }
+}
+
+impl Sum<FlatNumber> for Option<FlatNumber> {
+    fn sum<I: Iterator<Item = FlatNumber>>(iter: I) -> Self {
+        total(iter)
+    }
+}
+
+fn parse_numbers<N: Snailfish>(input: &str) -> Result<Vec<N>, AocError> {
+    input.lines()
+        .map(|line| line.parse())
+        .collect()
+}
+
+// The magnitude of the sum of the whole homework list.
+pub fn homework_magnitude<N: Snailfish>(input: &str) -> Result<usize, AocError> {
+    let numbers = parse_numbers::<N>(input)?;
+    let sum = total(numbers.into_iter())
+        .ok_or_else(|| AocError::Misc("No snailfish numbers".to_string()))?;
+
+    Ok(sum.magnitude())
+}
+
+// The largest magnitude from adding any two different numbers in the list.
+pub fn largest_pair_magnitude<N: Snailfish>(input: &str) -> Result<usize, AocError> {
+    let numbers = parse_numbers::<N>(input)?;
+
+    // Addition isn't commutative, so both orders of each pair count.
+    let mut largest = None;
+    for (i, a) in numbers.iter().enumerate() {
+        for (j, b) in numbers.iter().enumerate() {
+            if i != j {
+                let magnitude = (a.clone() + b.clone()).magnitude();
+                largest = largest.max(Some(magnitude));
+            }
+        }
+    }
+
+    largest.ok_or_else(|| AocError::Misc("Need at least two snailfish numbers".to_string()))
+}
+
+// Every step of summing the homework list, one list of steps per addition.
+// A first number that isn't already reduced gets a list of its own.
+pub fn trace_homework(input: &str) -> Result<Vec<Vec<Step>>, AocError> {
+    let mut numbers = parse_numbers::<Number>(input)?.into_iter();
+    let mut sum = numbers.next()
+        .ok_or_else(|| AocError::Misc("No snailfish numbers".to_string()))?;
+
+    let mut additions = Vec::new();
+    let mut steps = Vec::new();
+    sum.reduce_traced(&mut steps);
+    if !steps.is_empty() {
+        additions.push(steps);
+    }
+
+    for number in numbers {
+        let (next, steps) = sum.add_traced(number);
+        additions.push(steps);
+        sum = next;
+    }
+
+    Ok(additions)
+}
+
+// Both views sum the homework, or the numbers given as arguments instead.
+fn render_trace<F>(input: &str, args: &[String], format: F) -> Result<Vec<String>, AocError>
+    where F: Fn(&Step) -> String
+{
+    let input = if args.is_empty() { input.to_string() } else { args.join("\n") };
+    let frames = trace_homework(&input)?
+        .iter()
+        .map(|steps| steps.iter().map(&format).collect::<Vec<String>>().join("\n"))
+        .collect();
+
+    Ok(frames)
+}
+
+fn render_reduction(input: &str, args: &[String]) -> Result<Vec<String>, AocError> {
+    render_trace(input, args, |step| step.to_string())
+}
+
+fn render_actions(input: &str, args: &[String]) -> Result<Vec<String>, AocError> {
+    render_trace(input, args, |step| step.describe())
+}
+
+pub const VIEWS: &[View] = &[
+    View {
+        name: "reduce",
+        description: "each addition's reduction, like the worked example [NUMBER...]",
+        render: render_reduction,
+        args_replace_input: true
+    },
+    View {
+        name: "actions",
+        description: "what each explode and split acted on [NUMBER...]",
+        render: render_actions,
+        args_replace_input: true
+    }
+];
+
+pub fn parse(input: &str) -> Result<(), AocError> {
+    parse_numbers::<FlatNumber>(input)?;
+
+    Ok(())
+}
+
+// Both parts use the flat representation, which benches faster; see
+// `cargo bench --bench snailfish_benches`.
+pub fn part_one(input: &str) -> Result<String, AocError> {
+    Ok(homework_magnitude::<FlatNumber>(input)?.to_string())
+}
+
+pub fn part_two(input: &str) -> Result<String, AocError> {
+    Ok(largest_pair_magnitude::<FlatNumber>(input)?.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::{trace_homework, Action, FlatNumber, Number, Side, Snailfish};
+
+    fn parse(s: &str) -> Number {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn explodes_puzzle_examples() {
+        let examples = [
+            ("[[[[[9,8],1],2],3],4]", "[[[[0,9],2],3],4]"),
+            ("[7,[6,[5,[4,[3,2]]]]]", "[7,[6,[5,[7,0]]]]"),
+            ("[[6,[5,[4,[3,2]]]],1]", "[[6,[5,[7,0]]],3]"),
+            ("[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]", "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]"),
+            ("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]", "[[3,[2,[8,0]]],[9,[5,[7,0]]]]")
+        ];
+        for (before, after) in examples {
+            let mut number = parse(before);
+            assert!(number.explode().is_some());
+            assert_eq!(number.to_string(), after);
+        }
+    }
+
+    #[test]
+    fn adds_and_reduces() {
+        let sum = parse("[[[[4,3],4],4],[7,[[8,4],9]]]") + parse("[1,1]");
+        assert_eq!(sum.to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+
+        let numbers = ["[1,1]", "[2,2]", "[3,3]", "[4,4]", "[5,5]", "[6,6]"];
+        let sum: Option<Number> = numbers.iter().map(|n| parse(n)).sum();
+        assert_eq!(sum.unwrap().to_string(), "[[[[5,0],[7,4]],[5,5]],[6,6]]");
+
+        let sum: Option<Number> = std::iter::empty().sum();
+        assert_eq!(sum, None);
+    }
+
+    #[test]
+    fn traces_the_worked_example() {
+        let (sum, steps) = parse("[[[[4,3],4],4],[7,[[8,4],9]]]").add_traced(parse("[1,1]"));
+        let expected = [
+            "after addition: [[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]",
+            "after explode:  [[[[0,7],4],[7,[[8,4],9]]],[1,1]]",
+            "after explode:  [[[[0,7],4],[15,[0,13]]],[1,1]]",
+            "after split:    [[[[0,7],4],[[7,8],[0,13]]],[1,1]]",
+            "after split:    [[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]",
+            "after explode:  [[[[0,7],4],[[7,8],[6,0]]],[8,1]]"
+        ];
+        let lines: Vec<String> = steps.iter().map(|step| step.to_string()).collect();
+        assert_eq!(lines, expected);
+        assert_eq!(sum.to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+
+        use Side::*;
+        assert_eq!(steps[1].action, Action::Explode { path: vec![Left, Left, Left, Left], left: 4, right: 3 });
+        assert_eq!(steps[3].action, Action::Split { path: vec![Left, Right, Left], value: 15 });
+    }
+
+    #[test]
+    fn traces_every_addition_in_the_homework() {
+        let additions = trace_homework("[1,1]\n[2,2]\n[3,3]\n[4,4]\n[5,5]").unwrap();
+        assert_eq!(additions.len(), 4);
+        assert_eq!(additions[3].last().unwrap().result, "[[[[3,0],[5,3]],[4,4]],[5,5]]");
+    }
+
+    #[test]
+    fn computes_magnitudes() {
+        assert_eq!(parse("[[1,2],[[3,4],5]]").magnitude(), 143);
+        assert_eq!(parse("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]").magnitude(), 3488);
+    }
+
+    #[test]
+    fn flat_numbers_reduce_like_trees() {
+        let mut tree = parse("[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]");
+        let mut flat = FlatNumber::from(&tree);
+        while tree.explode().is_some() || tree.split().is_some() {
+            assert!(flat.explode() || flat.split());
+            assert_eq!(flat.to_string(), tree.to_string());
+        }
+        assert!(!flat.explode() && !flat.split());
+    }
+
+    #[test]
+    fn flat_numbers_explode_the_first_pair() {
+        // The second 9 and the 3 are neighbours at the same depth but not a
+        // pair.
+        let mut flat: FlatNumber = "[[[[1,[2,9]],[[3,9],0]],0],0]".parse().unwrap();
+        let mut tree = parse("[[[[1,[2,9]],[[3,9],0]],0],0]");
+        assert!(flat.explode() && tree.explode().is_some());
+        assert_eq!(flat.to_string(), "[[[[3,0],[[12,9],0]],0],0]");
+        assert_eq!(flat.to_string(), tree.to_string());
+    }
+
+    fn number() -> impl Strategy<Value = Number> {
+        (0..10u32).prop_map(Number::Literal).prop_recursive(5, 32, 2, |inner| {
+            (inner.clone(), inner).prop_map(|(left, right)| Number::Pair(Box::new(left), Box::new(right)))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn display_round_trips(number in number()) {
+            let reparsed: Number = number.to_string().parse().unwrap();
+            prop_assert_eq!(reparsed, number);
+        }
+
+        #[test]
+        fn representations_convert_both_ways(number in number()) {
+            let flat = FlatNumber::from(&number);
+            prop_assert_eq!(flat.magnitude(), number.magnitude());
+            prop_assert_eq!(Number::from(&flat), number);
+        }
+
+        #[test]
+        fn representations_add_the_same(a in number(), b in number()) {
+            let tree = a.clone() + b.clone();
+            let flat = FlatNumber::from(&a) + FlatNumber::from(&b);
+            prop_assert_eq!(flat.to_string(), tree.to_string());
+        }
+
+        #[test]
+        fn parse_round_trips(input in "[\\[\\],0-9]{0,30}") {
+            if let Ok(number) = input.parse::<Number>() {
+                let reparsed: Number = number.to_string().parse().unwrap();
+                prop_assert_eq!(reparsed, number);
+            }
+        }
+    }
+}
@@ -4,17 +4,15 @@ use crate::aoc_error::AocError;
 
 pub const NAME: &str = "Dumbo Octopus";
 
-const SIZE: usize = 10;
-
 #[derive(Debug)]
 struct Point(usize, usize);
 
 impl Point {
-    fn neighbors(&self) -> Vec<Self> {
+    fn neighbors(&self, width: usize, height: usize) -> Vec<Self> {
         let up = self.1 > 0;
-        let down = self.1 < SIZE - 1;
+        let down = self.1 < height - 1;
         let left = self.0 > 0;
-        let right = self.0 < SIZE - 1;
+        let right = self.0 < width - 1;
 
         let mut points = Vec::with_capacity(8);
 
@@ -30,49 +28,60 @@ impl Point {
         points
     }
 
-    fn index(&self) -> usize {
-        self.1 * SIZE + self.0
+    fn index(&self, width: usize) -> usize {
+        self.1 * width + self.0
     }
 
-    fn from_index(i: usize) -> Self {
-        Point(i % SIZE, i / SIZE)
+    fn from_index(i: usize, width: usize) -> Self {
+        Point(i % width, i / width)
     }
 }
 
 #[derive(Debug)]
 struct Octopuses {
-    grid: Vec<u32>
+    grid: Vec<u32>,
+    width: usize
 }
 
 impl FromStr for Octopuses {
     type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Octopuses {
-            grid: s.lines()
-                .flat_map(|l| l.chars())
-                .map(|c| c.to_digit(10).ok_or_else(|| AocError::Misc(format!("Bad char '{}'", c))))
-                .collect::<Result<Vec<u32>, AocError>>()?
-        })
+        let width = s.lines().next().map_or(0, |l| l.len());
+        let grid = s.lines()
+            .flat_map(|l| l.chars())
+            .map(|c| c.to_digit(10).ok_or_else(|| AocError::Misc(format!("Bad char '{}'", c))))
+            .collect::<Result<Vec<u32>, AocError>>()?;
+
+        if width == 0 || !grid.len().is_multiple_of(width) {
+            return Err(AocError::Misc("Octopus grid isn't rectangular".to_string()));
+        }
+
+        Ok(Octopuses { grid, width })
     }
 }
 
 impl Octopuses {
+    fn height(&self) -> usize {
+        self.grid.len() / self.width
+    }
+
     fn step(&mut self) -> usize {
         let mut to_flash = VecDeque::new();
 
         for (i, octopus) in self.grid.iter_mut().enumerate() {
             *octopus += 1;
 
-            if *octopus == 10 { to_flash.push_back(Point::from_index(i)); }
+            if *octopus == 10 { to_flash.push_back(Point::from_index(i, self.width)); }
         }
 
         while !to_flash.is_empty() {
             let next = to_flash.pop_front().unwrap();
 
-            for point in next.neighbors() {
-                self.grid[point.index()] += 1;
-                if self.grid[point.index()] == 10 { to_flash.push_back(point); }
+            for point in next.neighbors(self.width, self.height()) {
+                let index = point.index(self.width);
+                self.grid[index] += 1;
+                if self.grid[index] == 10 { to_flash.push_back(point); }
             }
         }
 
@@ -86,21 +95,30 @@ impl Octopuses {
     }
 }
 
-pub fn part_one(input: &str) -> Result<String, AocError> {
+pub fn parse(input: &str) -> Result<(), AocError> {
+    input.parse::<Octopuses>()?;
+
+    Ok(())
+}
+
+pub fn flashes_after(input: &str, steps: usize) -> Result<usize, AocError> {
     let mut octos: Octopuses = input.parse()?;
-    let total_flashes: usize = (0..100)
+
+    Ok((0..steps)
         .map(|_| octos.step())
-        .sum();
+        .sum())
+}
 
-    Ok(total_flashes.to_string())
+pub fn part_one(input: &str) -> Result<String, AocError> {
+    Ok(flashes_after(input, 100)?.to_string())
 }
 
 pub fn part_two(input: &str) -> Result<String, AocError> {
     let mut octos: Octopuses = input.parse()?;
+    let population = octos.grid.len();
 
     let synchro_flash = (1..usize::MAX)
-        .skip_while(|_| octos.step() < 100)
-        .next()
+        .find(|_| octos.step() == population)
         .unwrap();
 
     Ok(synchro_flash.to_string())
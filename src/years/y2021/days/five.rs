@@ -130,11 +130,21 @@ impl VentField2 {
     }
 }
 
-pub fn part_one(input: &str) -> Result<String, AocError> {
-    let lines = input
+fn parse_lines(input: &str) -> Result<Vec<Line>, AocError> {
+    input
         .lines()
         .map(|l| l.parse())
-        .collect::<Result<Vec<Line>, AocError>>()?;
+        .collect()
+}
+
+pub fn parse(input: &str) -> Result<(), AocError> {
+    parse_lines(input)?;
+
+    Ok(())
+}
+
+pub fn part_one(input: &str) -> Result<String, AocError> {
+    let lines = parse_lines(input)?;
     let mut field = VentField2::new();
 
     for line in lines {
@@ -149,10 +159,7 @@ pub fn part_one(input: &str) -> Result<String, AocError> {
 }
 
 pub fn part_two(input: &str) -> Result<String, AocError> {
-    let lines = input
-        .lines()
-        .map(|l| l.parse())
-        .collect::<Result<Vec<Line>, AocError>>()?;
+    let lines = parse_lines(input)?;
     let mut field = VentField2::new();
 
     for line in lines {
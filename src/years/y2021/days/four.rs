@@ -75,21 +75,21 @@ impl Board {
 
     fn has_won(&self) -> bool {
         SLICES.iter()
-            .map(|slice| slice.iter()
+            .any(|slice| slice.iter()
                             .map(|i| self.numbers[*i])
                             .all(|n| n.is_match()))
-            .any(|b| b)
     }
 
     fn score(&self) -> usize {
         self.numbers
             .iter()
-            .map(|n| usize::from(n))
+            .map(usize::from)
             .sum()
     }
 }
 
-pub fn part_one(input: &str) -> Result<String, AocError> {
+// The drawn numbers and the boards they're marked on.
+fn parse_game(input: &str) -> Result<(Vec<usize>, Vec<Board>), AocError> {
     let mut components = input.split("\n\n");
 
     let drawn_numbers = components.next()
@@ -98,10 +98,22 @@ pub fn part_one(input: &str) -> Result<String, AocError> {
         .map(|s| s.parse())
         .collect::<Result<Vec<usize>, ParseIntError>>()?;
 
-    let mut boards = components
+    let boards = components
         .map(|s| s.parse())
         .collect::<Result<Vec<Board>, AocError>>()?;
 
+    Ok((drawn_numbers, boards))
+}
+
+pub fn parse(input: &str) -> Result<(), AocError> {
+    parse_game(input)?;
+
+    Ok(())
+}
+
+pub fn part_one(input: &str) -> Result<String, AocError> {
+    let (drawn_numbers, mut boards) = parse_game(input)?;
+
     for number in drawn_numbers {
         for board in boards.iter_mut() {
             board.mark(number);
@@ -115,17 +127,7 @@ pub fn part_one(input: &str) -> Result<String, AocError> {
 }
 
 pub fn part_two(input: &str) -> Result<String, AocError> {
-    let mut components = input.split("\n\n");
-
-    let drawn_numbers = components.next()
-        .ok_or_else(|| AocError::Misc("No drawn numbers in input".to_string()))?
-        .split(",")
-        .map(|s| s.parse())
-        .collect::<Result<Vec<usize>, ParseIntError>>()?;
-
-    let mut boards = components
-        .map(|s| s.parse())
-        .collect::<Result<Vec<Board>, AocError>>()?;
+    let (drawn_numbers, mut boards) = parse_game(input)?;
 
     let mut wins = 0;
     let total = boards.len();
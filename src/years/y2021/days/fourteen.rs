@@ -35,6 +35,10 @@ impl FromStr for Rules {
                 let input: Vec<char> = input.chars().collect();
                 let output: Vec<char> = output.chars().collect();
 
+                if input.len() != 2 || output.len() != 1 {
+                    return Err(AocError::Misc(format!("Bad rule \"{}\"", l)));
+                }
+
                 Ok(((input[0], input[1]), output[0]))
             })
             .collect::<Result<HashMap<(char, char), char>, AocError>>()?;
@@ -70,7 +74,7 @@ impl PolymerMemo {
             counts.clone()
         } else {
             let counts = seed.counts(rules, self);
-            self.memo.insert(seed.clone(), counts.clone());
+            self.memo.insert(*seed, counts.clone());
             counts
         }
     }
@@ -79,7 +83,7 @@ impl PolymerMemo {
 impl AtomCounts {
     fn merge(&mut self, other: &AtomCounts) {
         for (key, value) in other.counts.iter() {
-            if let Some(n) = self.counts.get_mut(&key) {
+            if let Some(n) = self.counts.get_mut(key) {
                 *n += value;
             } else {
                 self.counts.insert(*key, *value);
@@ -164,22 +168,33 @@ impl Polymer {
     }
 }
 
-pub fn part_one(input: &str) -> Result<String, AocError> {
+// The polymer template and the pair insertion rules.
+fn parse_instructions(input: &str) -> Result<(Polymer, Rules), AocError> {
     let (polymer, rules) = input.split_once("\n\n")
         .ok_or_else(|| AocError::Misc("No blank line input".to_string()))?;
-    let polymer: Polymer = polymer.parse()?;
-    let rules: Rules = rules.parse()?;
 
-    let counts = polymer.counts_after_iterations(&rules, 10);
-    Ok((counts.max() - counts.min()).to_string())
+    Ok((polymer.parse()?, rules.parse()?))
 }
 
-pub fn part_two(input: &str) -> Result<String, AocError> {
-    let (polymer, rules) = input.split_once("\n\n")
-        .ok_or_else(|| AocError::Misc("No blank line input".to_string()))?;
-    let polymer: Polymer = polymer.parse()?;
-    let rules: Rules = rules.parse()?;
+pub fn parse(input: &str) -> Result<(), AocError> {
+    parse_instructions(input)?;
+
+    Ok(())
+}
 
-    let counts = polymer.counts_after_iterations(&rules, 40);
-    Ok((counts.max() - counts.min()).to_string())
+// Difference between the most and least common elements after the given
+// number of insertion steps.
+pub fn element_spread(input: &str, iterations: usize) -> Result<usize, AocError> {
+    let (polymer, rules) = parse_instructions(input)?;
+
+    let counts = polymer.counts_after_iterations(&rules, iterations);
+    Ok(counts.max() - counts.min())
+}
+
+pub fn part_one(input: &str) -> Result<String, AocError> {
+    Ok(element_spread(input, 10)?.to_string())
+}
+
+pub fn part_two(input: &str) -> Result<String, AocError> {
+    Ok(element_spread(input, 40)?.to_string())
 }
\ No newline at end of file
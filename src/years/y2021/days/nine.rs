@@ -38,13 +38,13 @@ impl LavaTubeArea {
         let mut positions = Vec::with_capacity(4);
 
         // Mod 0 is left edge
-        if position % self.width != 0 { positions.push(position - 1); }
+        if !position.is_multiple_of(self.width) { positions.push(position - 1); }
         // right edge
         if position % self.width != (self.width - 1) { positions.push(position + 1); }
         // top row
         if position >= self.width { positions.push(position - self.width); }
         // bottom row
-        if position <= (self.locations.len() - self.width) { positions.push(position + self.width); }
+        if position < (self.locations.len() - self.width) { positions.push(position + self.width); }
 
         positions
     }
@@ -101,6 +101,12 @@ impl LavaTubeArea {
     }
 }
 
+pub fn parse(input: &str) -> Result<(), AocError> {
+    input.parse::<LavaTubeArea>()?;
+
+    Ok(())
+}
+
 pub fn part_one(input: &str) -> Result<String, AocError> {
     let area: LavaTubeArea = input.parse()?;
 
@@ -3,11 +3,21 @@ use crate::aoc_error::AocError;
 
 pub const NAME: &str = "Sonar Sweep";
 
-pub fn part_one(input: &str) -> Result<String, AocError> {
-    let nums = input
+fn parse_depths(input: &str) -> Result<Vec<usize>, AocError> {
+    Ok(input
         .lines()
         .map(|l| l.parse())
-        .collect::<Result<Vec<usize>, ParseIntError>>()?;
+        .collect::<Result<Vec<usize>, ParseIntError>>()?)
+}
+
+pub fn parse(input: &str) -> Result<(), AocError> {
+    parse_depths(input)?;
+
+    Ok(())
+}
+
+pub fn part_one(input: &str) -> Result<String, AocError> {
+    let nums = parse_depths(input)?;
 
     let steps_up = nums.iter()
         .zip(nums.iter().skip(1))
@@ -18,10 +28,7 @@ pub fn part_one(input: &str) -> Result<String, AocError> {
 }
 
 pub fn part_two(input: &str) -> Result<String, AocError> {
-    let nums = input
-        .lines()
-        .map(|l| l.parse())
-        .collect::<Result<Vec<usize>, ParseIntError>>()?;
+    let nums = parse_depths(input)?;
 
     let window_sums: Vec<usize> = nums.iter()
         .zip(nums.iter().skip(1))
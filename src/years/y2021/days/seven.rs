@@ -18,11 +18,7 @@ fn crab_mean(positions: &[usize]) -> usize {
 }
 
 fn fuel_cost_p1(position: usize, target: usize) -> usize {
-    if position < target {
-        target - position
-    } else {
-        position - target
-    }
+    target.abs_diff(position)
 }
 
 // Fuel costs in part 2 are triangle numbers
@@ -31,11 +27,21 @@ fn fuel_cost_p2(position: usize, target: usize) -> usize {
     (distance * (distance + 1)) / 2
 }
 
-pub fn part_one(input: &str) -> Result<String, AocError> {
-    let mut positions = input
+fn parse_positions(input: &str) -> Result<Vec<usize>, AocError> {
+    Ok(input
         .split(",")
         .map(|s| s.parse())
-        .collect::<Result<Vec<usize>, ParseIntError>>()?;
+        .collect::<Result<Vec<usize>, ParseIntError>>()?)
+}
+
+pub fn parse(input: &str) -> Result<(), AocError> {
+    parse_positions(input)?;
+
+    Ok(())
+}
+
+pub fn part_one(input: &str) -> Result<String, AocError> {
+    let mut positions = parse_positions(input)?;
     positions.sort();
 
     let best_gather_spot = crab_median(&positions);
@@ -49,10 +55,7 @@ pub fn part_one(input: &str) -> Result<String, AocError> {
 }
 
 pub fn part_two(input: &str) -> Result<String, AocError> {
-    let positions = input
-        .split(",")
-        .map(|s| s.parse())
-        .collect::<Result<Vec<usize>, ParseIntError>>()?;
+    let positions = parse_positions(input)?;
 
     let low_candidate = crab_mean(&positions);
     let high_candidate = low_candidate + 1;
@@ -0,0 +1,621 @@
+use std::collections::HashSet;
+use std::fmt::{self, Display, Formatter};
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use crate::aoc_error::AocError;
+use crate::years::View;
+
+pub const NAME: &str = "Trick Shot";
+
+#[derive(Debug, Copy, Clone)]
+struct Point(isize, isize);
+
+impl Point {
+    fn x(&self) -> isize { self.0 }
+    fn y(&self) -> isize { self.1 }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Area(Point, Point);
+
+impl Area {
+    fn left(&self) -> isize {
+        if self.0.x() < self.1.x() { self.0.x() } else { self.1.x() }
+    }
+
+    fn right(&self) -> isize {
+        if self.0.x() > self.1.x() { self.0.x() } else { self.1.x() }
+    }
+
+    fn top(&self) -> isize {
+        if self.0.y() > self.1.y() { self.0.y() } else { self.1.y() }
+    }
+
+    fn bottom(&self) -> isize {
+        if self.0.y() < self.1.y() { self.0.y() } else { self.1.y() }
+    }
+
+    fn contains(&self, point: &Point) -> bool {
+        self.left() <= point.x() && self.right() >= point.x() &&
+        self.top() >= point.y() && self.bottom() <= point.y()
+    }
+
+    // Every horizontal velocity that could reach the target, which can be to
+    // either side of the launch point.
+    fn vx_range(&self) -> RangeInclusive<isize> {
+        self.left().min(0)..=self.right().max(0)
+    }
+
+    // Every vertical velocity that could reach the target. Going up at vy, the
+    // probe comes back down through y=0 at -vy and its next step is to
+    // -(vy + 1), so below the launch point that can't be past the bottom, and
+    // above it the first step can't be past the top. A target across y=0 can
+    // also be hit as the probe comes back through y=0 at step 2vy + 1, as long
+    // as it's still lined up sideways by then.
+    fn vy_range(&self) -> RangeInclusive<isize> {
+        let mut highest = self.top().max(-self.bottom() - 1);
+        if self.bottom() <= 0 && self.top() >= 0 {
+            highest = highest.max((self.last_step_in_line() - 1) / 2);
+        }
+
+        self.bottom().min(0)..=highest
+    }
+
+    // The last step at which any vx has the probe lined up with the target
+    // sideways, for targets where it can't stop lined up.
+    fn last_step_in_line(&self) -> isize {
+        let mut last = 0;
+        for vx in self.vx_range() {
+            let (mut x, mut v, mut step) = (0, vx, 0);
+            while v != 0 {
+                x += v;
+                v -= v.signum();
+                step += 1;
+                if self.left() <= x && x <= self.right() {
+                    last = last.max(step);
+                }
+            }
+        }
+
+        last
+    }
+
+    // Once the probe stops moving sideways it falls straight down forever, and
+    // however high it's thrown it comes back through y=0. So if the target
+    // covers y=0 somewhere the probe can stop, any vy high enough will do.
+    fn has_infinite_solutions(&self) -> bool {
+        if self.bottom() > 0 || self.top() < 0 {
+            return false;
+        }
+
+        let distance = if self.right() < 0 { -self.right() } else { self.left().max(0) };
+        let limit = if self.right() < 0 { -self.left() } else { self.right() };
+        (0..).map(|n| n * (n + 1) / 2)
+            .take_while(|stop| *stop <= limit)
+            .any(|stop| stop >= distance)
+    }
+}
+
+impl FromStr for Area {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bad_area = || AocError::Misc(format!("Bad target area \"{}\"", s));
+        let ranges = s.trim().strip_prefix("target area: ").ok_or_else(bad_area)?;
+        let (x, y) = ranges.split_once(", ").ok_or_else(bad_area)?;
+
+        let range = |text: &str, axis: &str| -> Result<(isize, isize), AocError> {
+            let (from, to) = text.strip_prefix(axis)
+                .and_then(|range| range.split_once(".."))
+                .ok_or_else(bad_area)?;
+            Ok((from.parse()?, to.parse()?))
+        };
+        let (x1, x2) = range(x, "x=")?;
+        let (y1, y2) = range(y, "y=")?;
+
+        Ok(Area(Point(x1, y1), Point(x2, y2)))
+    }
+}
+
+impl Display for Area {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "target area: x={}..{}, y={}..{}", self.left(), self.right(), self.bottom(), self.top())
+    }
+}
+
+// Follows the probe a step at a time, for drawing and for checking the
+// solver against.
+#[derive(Debug, Copy, Clone)]
+struct Arc {
+    vx: isize,
+    vy: isize,
+    loc: Point
+}
+
+impl Arc {
+    fn step(&self) -> Self {
+        Arc {
+            vx: if self.vx < 0 {
+                    self.vx + 1
+                } else if self.vx > 0 {
+                    self.vx - 1
+                } else {
+                    self.vx
+                },
+            vy: self.vy - 1,
+            loc: Point(self.loc.x() + self.vx, self.loc.y() + self.vy)
+        }
+    }
+
+    // Falling and below the area means it'll never get back up to it.
+    fn is_past(&self, area: &Area) -> bool {
+        self.vy < 0 && self.loc.y() < area.bottom()
+    }
+
+    // Where the probe is after each step, until it's in the area, falling
+    // past it, or `max_steps` along.
+    fn path(&self, area: &Area, max_steps: usize) -> Vec<Point> {
+        let mut arc = *self;
+        let mut path = Vec::new();
+        while !area.contains(&arc.loc) && !arc.is_past(area) && path.len() < max_steps {
+            arc = arc.step();
+            path.push(arc.loc);
+        }
+
+        path
+    }
+
+    #[cfg(test)]
+    fn passes_through(&self, area: &Area) -> bool {
+        // Falling past the area, or beside it and not heading back towards
+        // it, means it never will get there.
+        let x = self.loc.x();
+        let drifting_away = (x > area.right() && self.vx >= 0) || (x < area.left() && self.vx <= 0);
+        if self.is_past(area) || drifting_away {
+            false
+        } else if area.contains(&self.loc) {
+            true
+        } else {
+            self.step().passes_through(area)
+        }
+    }
+}
+
+// Both axes start at some speed v and slow by one each step, so after n steps
+// they've covered v + (v - 1) + ... + (v - n + 1), which is n * v - T(n - 1)
+// where T is the triangle numbers. Sideways that only holds until the probe
+// stops at step v.
+fn distance(v: isize, n: isize) -> isize {
+    n * v - n * (n - 1) / 2
+}
+
+// The steps, from 1 on, at which the distance is at least `at_least`. Since
+// distance is a downward parabola in n they're all in a row, between the
+// roots of n^2 - (2v + 1)n + 2 * at_least = 0. Floating point gets within a
+// step of the roots, and then they're nudged to be exact.
+fn steps_reaching(v: isize, at_least: isize) -> Option<RangeInclusive<isize>> {
+    let b = (2 * v + 1) as f64;
+    let discriminant = b * b - 8. * at_least as f64;
+    if discriminant < 0. {
+        return None;
+    }
+
+    let reaches = |n: isize| distance(v, n) >= at_least;
+    let mut first = (((b - discriminant.sqrt()) / 2.).ceil() as isize).max(1);
+    let mut last = ((b + discriminant.sqrt()) / 2.).floor() as isize;
+    while first > 1 && reaches(first - 1) { first -= 1; }
+    while first <= last && !reaches(first) { first += 1; }
+    while reaches(last + 1) { last += 1; }
+    while last >= first && !reaches(last) { last -= 1; }
+
+    if first <= last { Some(first..=last) } else { None }
+}
+
+// The steps the probe's x position is in `left..=right` for, with isize::MAX
+// meaning forever once it stops inside.
+fn x_steps(vx: isize, left: isize, right: isize) -> Option<RangeInclusive<isize>> {
+    if vx < 0 {
+        return x_steps(-vx, -right, -left);
+    }
+
+    let stop = distance(vx, vx);
+    if stop < left || right < 0 {
+        return None;
+    }
+
+    let first = if left <= 0 { 1 } else { *steps_reaching(vx, left)?.start() };
+    let last = if stop <= right {
+        isize::MAX
+    } else {
+        steps_reaching(vx, right + 1)?.start() - 1
+    };
+
+    if first <= last { Some(first..=last) } else { None }
+}
+
+// The steps the probe's y position is in `bottom..=top` for. Above the launch
+// point that's once on the way up and once on the way down.
+fn y_steps(vy: isize, bottom: isize, top: isize) -> Vec<RangeInclusive<isize>> {
+    let Some(above_bottom) = steps_reaching(vy, bottom) else {
+        return Vec::new();
+    };
+
+    match steps_reaching(vy, top + 1) {
+        None => vec![above_bottom],
+        Some(above_top) => [
+            *above_bottom.start()..=(*above_top.start() - 1),
+            (*above_top.end() + 1)..=*above_bottom.end()
+        ].into_iter().filter(|steps| !steps.is_empty()).collect()
+    }
+}
+
+// Every launch velocity that lands in the target, worked out from when each
+// axis is lined up with it rather than by following the probe.
+fn valid_velocities(target: &Area) -> Vec<(isize, isize)> {
+    let xs: Vec<(isize, RangeInclusive<isize>)> = target.vx_range()
+        .filter_map(|vx| x_steps(vx, target.left(), target.right()).map(|steps| (vx, steps)))
+        .collect();
+
+    let mut velocities = Vec::new();
+    for vy in target.vy_range() {
+        let ys = y_steps(vy, target.bottom(), target.top());
+        for (vx, x) in &xs {
+            if ys.iter().any(|y| y.start() <= x.end() && x.start() <= y.end()) {
+                velocities.push((*vx, vy));
+            }
+        }
+    }
+
+    velocities
+}
+
+fn parse_target(input: &str) -> Result<Area, AocError> {
+    let area: Area = input.parse()?;
+    if area.has_infinite_solutions() {
+        return Err(AocError::Misc(format!("Infinitely many velocities reach {}", area)));
+    }
+
+    Ok(area)
+}
+
+pub fn parse(input: &str) -> Result<(), AocError> {
+    parse_target(input)?;
+
+    Ok(())
+}
+
+pub fn part_one(input: &str) -> Result<String, AocError> {
+    let target = parse_target(input)?;
+    let vy = valid_velocities(&target)
+        .into_iter()
+        .map(|(_, vy)| vy)
+        .max()
+        .ok_or_else(|| AocError::Misc("No solution".to_string()))?;
+
+    // The probe peaks when it stops going up at step vy, or at the launch
+    // point if it's thrown downwards.
+    let vy = vy.max(0);
+    Ok(distance(vy, vy).to_string())
+}
+
+pub fn part_two(input: &str) -> Result<String, AocError> {
+    let target = parse_target(input)?;
+
+    Ok(valid_velocities(&target).len().to_string())
+}
+
+// Plots which velocities land in the target, vx across and vy down, trimmed
+// to the ones that do.
+fn render_velocities(input: &str, args: &[String]) -> Result<Vec<String>, AocError> {
+    let input = if args.is_empty() { input.to_string() } else { args.join(" ") };
+    let velocities = valid_velocities(&parse_target(&input)?);
+    if velocities.is_empty() {
+        return Ok(vec!["No velocities reach the target".to_string()]);
+    }
+
+    let vxs = velocities.iter().map(|(vx, _)| *vx);
+    let vys = velocities.iter().map(|(_, vy)| *vy);
+    let (left, right) = (vxs.clone().min().unwrap(), vxs.max().unwrap());
+    let (bottom, top) = (vys.clone().min().unwrap(), vys.max().unwrap());
+
+    let mut lines = vec![format!(
+        "{} velocities, vx from {} to {} across, vy from {} down to {}",
+        velocities.len(), left, right, top, bottom
+    )];
+    for vy in (bottom..=top).rev() {
+        lines.push((left..=right)
+            .map(|vx| if velocities.contains(&(vx, vy)) { '#' } else { '.' })
+            .collect());
+    }
+
+    Ok(vec![lines.join("\n")])
+}
+
+// Fast enough launches take forever to come back down and cover huge areas,
+// so the views only follow the probe so far and only draw so much of it.
+const MAX_STEPS: usize = 10_000;
+const MAX_CANVAS: usize = 4_000_000;
+
+// Where the svg view writes to without a file argument.
+const SVG_FILE: &str = "trajectory.svg";
+
+fn parse_velocity(args: &[String]) -> Result<(isize, isize), AocError> {
+    let velocity = args.first()
+        .ok_or_else(|| AocError::Misc("Needs a launch velocity like 7,2".to_string()))?;
+    let (vx, vy) = velocity.split_once(',')
+        .ok_or_else(|| AocError::Misc(format!("Bad launch velocity \"{}\"", velocity)))?;
+
+    Ok((vx.trim().parse()?, vy.trim().parse()?))
+}
+
+// The launch, the path, and the target, from a view's velocity and optional
+// target arguments.
+fn launch(input: &str, args: &[String]) -> Result<(Area, Vec<Point>), AocError> {
+    let (vx, vy) = parse_velocity(args)?;
+    let input = if args.len() > 1 { args[1..].join(" ") } else { input.to_string() };
+    let target: Area = input.parse()?;
+    let path = Arc { vx, vy, loc: Point(0, 0) }.path(&target, MAX_STEPS);
+
+    Ok((target, path))
+}
+
+// The corners of everything that gets drawn, as (left, bottom, right, top).
+fn bounds(target: &Area, path: &[Point]) -> (isize, isize, isize, isize) {
+    path.iter().fold(
+        (target.left().min(0), target.bottom().min(0), target.right().max(0), target.top().max(0)),
+        |(left, bottom, right, top), point| {
+            (left.min(point.x()), bottom.min(point.y()), right.max(point.x()), top.max(point.y()))
+        }
+    )
+}
+
+fn describe(target: &Area, path: &[Point]) -> String {
+    match path.last() {
+        Some(point) if target.contains(point) => {
+            format!("Lands in the target at {},{} after {} steps", point.x(), point.y(), path.len())
+        },
+        _ if path.len() == MAX_STEPS => format!("Still hasn't reached the target after {} steps", MAX_STEPS),
+        _ => "Misses the target".to_string()
+    }
+}
+
+// Draws the path like the puzzle does, with S for the launch, # for where the
+// probe is after each step, and T for the target.
+fn draw(target: &Area, path: &[Point]) -> Result<String, AocError> {
+    let (left, bottom, right, top) = bounds(target, path);
+    let (width, height) = ((right - left + 1) as usize, (top - bottom + 1) as usize);
+    if width.saturating_mul(height) > MAX_CANVAS {
+        return Err(AocError::Misc(format!(
+            "A {} by {} picture is too big to draw, the svg view can show it", width, height
+        )));
+    }
+
+    let steps: HashSet<(isize, isize)> = path.iter().map(|p| (p.x(), p.y())).collect();
+    let mut lines = Vec::new();
+    for y in (bottom..=top).rev() {
+        lines.push((left..=right)
+            .map(|x| {
+                let point = Point(x, y);
+                if x == 0 && y == 0 {
+                    'S'
+                } else if steps.contains(&(x, y)) {
+                    '#'
+                } else if target.contains(&point) {
+                    'T'
+                } else {
+                    '.'
+                }
+            })
+            .collect::<String>());
+    }
+
+    Ok(lines.join("\n"))
+}
+
+// The same picture as an SVG, flipping y so up is up.
+fn svg(target: &Area, path: &[Point]) -> String {
+    let (left, bottom, right, top) = bounds(target, path);
+    let points: Vec<String> = std::iter::once(Point(0, 0))
+        .chain(path.iter().copied())
+        .map(|point| format!("{},{}", point.x(), -point.y()))
+        .collect();
+
+    let mut lines = vec![
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\">",
+            left - 1, -top - 1, right - left + 2, top - bottom + 2
+        ),
+        format!(
+            "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"#9ecae1\"/>",
+            target.left() as f64 - 0.5, -target.top() as f64 - 0.5,
+            target.right() - target.left() + 1, target.top() - target.bottom() + 1
+        ),
+        format!(
+            "  <polyline points=\"{}\" fill=\"none\" stroke=\"#555\" stroke-width=\"1\" \
+             vector-effect=\"non-scaling-stroke\"/>",
+            points.join(" ")
+        )
+    ];
+    for point in path {
+        lines.push(format!("  <circle cx=\"{}\" cy=\"{}\" r=\"0.4\" fill=\"#e6550d\"/>", point.x(), -point.y()));
+    }
+    lines.push("  <circle cx=\"0\" cy=\"0\" r=\"0.6\" fill=\"#31a354\"/>".to_string());
+    lines.push("</svg>".to_string());
+
+    lines.join("\n")
+}
+
+fn render_trajectory(input: &str, args: &[String]) -> Result<Vec<String>, AocError> {
+    let (target, path) = launch(input, args)?;
+    Ok(vec![draw(&target, &path)?, describe(&target, &path)])
+}
+
+// Writes the SVG to a file given after the velocity, or SVG_FILE if there
+// isn't one.
+fn render_svg(input: &str, args: &[String]) -> Result<Vec<String>, AocError> {
+    let (file, args) = match args.get(1) {
+        Some(file) if file.ends_with(".svg") => (file.as_str(), [&args[..1], &args[2..]].concat()),
+        _ => (SVG_FILE, args.to_vec())
+    };
+    let (target, path) = launch(input, &args)?;
+    std::fs::write(file, svg(&target, &path))?;
+
+    Ok(vec![format!("Wrote {}, {}", file, describe(&target, &path).to_lowercase())])
+}
+
+pub const VIEWS: &[View] = &[
+    View {
+        name: "velocities",
+        description: "every launch velocity that lands in the target [TARGET]",
+        render: render_velocities,
+        args_replace_input: true
+    },
+    View {
+        name: "trajectory",
+        description: "the probe's path for a launch velocity VX,VY [TARGET]",
+        render: render_trajectory,
+        args_replace_input: false
+    },
+    View {
+        name: "svg",
+        description: "the probe's path as an SVG file, trajectory.svg by default VX,VY [FILE.svg] [TARGET]",
+        render: render_svg,
+        args_replace_input: false
+    }
+];
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part_one, part_two, render_svg, render_trajectory, valid_velocities, Arc, Area, Point};
+
+    // Searches a box wide enough to hold every velocity that could land,
+    // without leaning on the solver's own ranges.
+    fn brute_force(target: &Area) -> Vec<(isize, isize)> {
+        let reach = target.left().abs() + target.right().abs() + target.bottom().abs() + target.top().abs();
+        let mut velocities = Vec::new();
+        for vy in -reach..=reach {
+            for vx in -reach..=reach {
+                if (Arc { vx, vy, loc: Point(0, 0) }).passes_through(target) {
+                    velocities.push((vx, vy));
+                }
+            }
+        }
+        velocities
+    }
+
+    #[test]
+    fn agrees_with_following_the_probe() {
+        let targets = [
+            "target area: x=20..30, y=-10..-5",
+            "target area: x=282..314, y=-80..-45",
+            "target area: x=-30..-20, y=-10..-5",
+            "target area: x=2..3, y=5..6",
+            "target area: x=-5..7, y=-12..-3",
+            "target area: x=6..40, y=3..30",
+            "target area: x=1..1, y=-1..-1",
+            "target area: x=100..101, y=-2..-1",
+            "target area: x=100..101, y=-1..1"
+        ];
+        for target in targets {
+            let target: Area = target.parse().unwrap();
+            assert_eq!(valid_velocities(&target), brute_force(&target), "{}", target);
+        }
+    }
+
+    #[test]
+    fn mirrors_targets_to_the_left() {
+        assert_eq!(part_one("target area: x=-30..-20, y=-10..-5").unwrap(), "45");
+        assert_eq!(part_two("target area: x=-30..-20, y=-10..-5").unwrap(), "112");
+        // Ends can come in either order.
+        assert_eq!(part_two("target area: x=-20..-30, y=-5..-10").unwrap(), "112");
+    }
+
+    #[test]
+    fn reaches_targets_above_the_launch_point() {
+        // (2, 6), (3, 6), (2, 5), (3, 5), and (2, 3), with the highest going
+        // through the top of the target on its first step.
+        assert_eq!(part_one("target area: x=2..3, y=5..6").unwrap(), "21");
+        assert_eq!(part_two("target area: x=2..3, y=5..6").unwrap(), "5");
+    }
+
+    #[test]
+    fn reaches_targets_across_the_launch_height() {
+        // (22, 2) only gets there at (100, 0) as it comes back down.
+        assert_eq!(part_two("target area: x=100..101, y=-1..1").unwrap(), "9");
+        assert_eq!(part_one("target area: x=100..101, y=-1..1").unwrap(), "3");
+    }
+
+    #[test]
+    fn rejects_bad_targets() {
+        assert!(parse("target area: x=20..30, y=-10..-5").is_ok());
+        assert!(parse("target area: x=20..30, y=-10..-5\n").is_ok());
+        assert!(parse("target area: x=20..30").is_err());
+        assert!(parse("target area: y=-10..-5, x=20..30").is_err());
+        assert!(parse("target area: x=20..thirty, y=-10..-5").is_err());
+        assert!(parse("x=20..30, y=-10..-5").is_err());
+        assert!(parse("").is_err());
+        // Any vy high enough falls back through (3, 0).
+        assert!(parse("target area: x=2..4, y=-2..2").is_err());
+        assert!(parse("target area: x=4..5, y=-2..2").is_ok());
+    }
+
+    #[test]
+    fn draws_trajectories_like_the_puzzle() {
+        let args = ["7,2".to_string()];
+        let frames = render_trajectory("target area: x=20..30, y=-10..-5", &args).unwrap();
+        assert_eq!(frames[0], "\
+.............#....#............
+.......#..............#........
+...............................
+S........................#.....
+...............................
+...............................
+...........................#...
+...............................
+....................TTTTTTTTTTT
+....................TTTTTTTTTTT
+....................TTTTTTTT#TT
+....................TTTTTTTTTTT
+....................TTTTTTTTTTT
+....................TTTTTTTTTTT");
+        assert_eq!(frames[1], "Lands in the target at 28,-7 after 7 steps");
+
+        let args: Vec<String> = "17,-4 target area: x=20..30, y=-10..-5".split(' ').map(String::from).collect();
+        let frames = render_trajectory("", &args).unwrap();
+        assert_eq!(frames[1], "Misses the target");
+        assert!(frames[0].lines().last().unwrap().ends_with('#'));
+
+        assert!(render_trajectory("target area: x=20..30, y=-10..-5", &[]).is_err());
+        assert!(render_trajectory("target area: x=20..30, y=-10..-5", &["7".to_string()]).is_err());
+    }
+
+    #[test]
+    fn draws_svgs() {
+        let file = std::env::temp_dir().join(format!("aoc21-day17-{}.svg", std::process::id()));
+        let args = ["6,3".to_string(), file.to_str().unwrap().to_string()];
+        let frames = render_svg("target area: x=20..30, y=-10..-5", &args).unwrap();
+        assert!(frames[0].ends_with("lands in the target at 21,-9 after 9 steps"), "{}", frames[0]);
+
+        let svg = std::fs::read_to_string(&file).unwrap();
+        std::fs::remove_file(&file).unwrap();
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"-1 -7 32 18\">"), "{}", svg);
+        assert!(svg.contains("points=\"0,0 6,-3 11,-5 15,-6"), "{}", svg);
+        assert!(svg.ends_with("</svg>"));
+    }
+
+    #[test]
+    fn caps_how_much_gets_drawn() {
+        let target = "target area: x=20..30, y=-10..-5";
+        let error = render_trajectory(target, &["0,100000".to_string()]).unwrap_err().to_string();
+        assert!(error.contains("too big to draw"), "{}", error);
+        assert!(render_trajectory(target, &["1000000000,0".to_string()]).is_err());
+
+        let file = std::env::temp_dir().join(format!("aoc21-day17-capped-{}.svg", std::process::id()));
+        let args = ["0,100000".to_string(), file.to_str().unwrap().to_string()];
+        let frames = render_svg(target, &args).unwrap();
+        assert!(frames[0].ends_with("still hasn't reached the target after 10000 steps"), "{}", frames[0]);
+        let svg = std::fs::read_to_string(&file).unwrap();
+        std::fs::remove_file(&file).unwrap();
+        assert_eq!(svg.matches("<circle").count(), 10_001);
+    }
+}
@@ -15,7 +15,11 @@ impl FromStr for LanternfishPopulation {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut timers = [0; 9];
         for time in s.split(",").map(|s| s.parse::<usize>()) {
-            timers[time?] += 1;
+            let time = time?;
+            if time >= timers.len() {
+                return Err(AocError::Misc(format!("Bad lanternfish timer {}", time)));
+            }
+            timers[time] += 1;
         }
 
         Ok(LanternfishPopulation { timers })
@@ -37,22 +41,26 @@ impl LanternfishPopulation {
     }
 }
 
-pub fn part_one(input: &str) -> Result<String, AocError> {
-    let mut pop: LanternfishPopulation = input.parse()?;
-
-    for _ in 0..80 {
-        pop.step();
-    }
+pub fn parse(input: &str) -> Result<(), AocError> {
+    input.parse::<LanternfishPopulation>()?;
 
-    Ok(pop.total_pop().to_string())
+    Ok(())
 }
 
-pub fn part_two(input: &str) -> Result<String, AocError> {
+pub fn population_after(input: &str, days: usize) -> Result<usize, AocError> {
     let mut pop: LanternfishPopulation = input.parse()?;
 
-    for _ in 0..256 {
+    for _ in 0..days {
         pop.step();
     }
 
-    Ok(pop.total_pop().to_string())
+    Ok(pop.total_pop())
+}
+
+pub fn part_one(input: &str) -> Result<String, AocError> {
+    Ok(population_after(input, 80)?.to_string())
+}
+
+pub fn part_two(input: &str) -> Result<String, AocError> {
+    Ok(population_after(input, 256)?.to_string())
 }
\ No newline at end of file
@@ -0,0 +1,812 @@
+use num_bigint::BigUint;
+use crate::aoc_error::AocError;
+use crate::bit_reader::BitReader;
+use crate::input::Lcg;
+use crate::years::View;
+
+pub const NAME: &str = "Packet Decoder";
+
+// Everything that walks a packet tree recurses once per level, so decoding
+// stops at this depth rather than letting a deep enough transmission
+// overflow the stack. Transmissions can still be as long as they like.
+const MAX_DEPTH: usize = 250;
+
+// A transmission being decoded, along with the bit range each packet was read
+// from, in the order their headers were read, and how deep the packet being
+// read is nested.
+#[derive(Debug)]
+struct Bits {
+    reader: BitReader,
+    packet_spans: Vec<(usize, usize)>,
+    depth: usize
+}
+
+impl Bits {
+    fn new(s: &str) -> Result<Self, AocError> {
+        Ok(Bits { reader: BitReader::from_hex(s)?, packet_spans: Vec::new(), depth: 0 })
+    }
+
+    fn position(&self) -> usize {
+        self.reader.position()
+    }
+
+    fn read(&mut self, width: usize) -> Result<usize, AocError> {
+        Ok(self.reader.read(width)? as usize)
+    }
+}
+
+// Bits packed eight to a byte, most significant first, with anything past
+// `len` in the last byte left as zeroes.
+#[derive(Debug)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    len: usize
+}
+
+impl BitWriter {
+    fn new() -> Self {
+        BitWriter { bytes: Vec::new(), len: 0 }
+    }
+
+    fn write(&mut self, value: usize, bit_count: usize) {
+        for shift in (0..bit_count).rev() {
+            if self.len.is_multiple_of(8) {
+                self.bytes.push(0);
+            }
+            if (value >> shift) & 1 == 1 {
+                *self.bytes.last_mut().unwrap() |= 0x80 >> (self.len % 8);
+            }
+            self.len += 1;
+        }
+    }
+
+    fn append(&mut self, other: &BitWriter) {
+        if self.len.is_multiple_of(8) {
+            self.bytes.extend(&other.bytes);
+            self.len += other.len;
+            return;
+        }
+
+        for (i, byte) in other.bytes.iter().enumerate() {
+            let bit_count = (other.len - i * 8).min(8);
+            self.write((*byte >> (8 - bit_count)) as usize, bit_count);
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    // Pads the end with zeroes out to a whole byte, like the puzzle's
+    // transmissions are.
+    fn to_hex(&self) -> String {
+        self.bytes.iter().map(|byte| format!("{:02X}", byte)).collect()
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum LengthType {
+    // Length type 0, a 15 bit count of the sub-packets' total length in bits
+    TotalBits,
+    // Length type 1, an 11 bit count of immediate sub-packets
+    PacketCount
+}
+
+// The numbers packets can be evaluated in. Machine integers are fast but
+// report overflow, big integers always get the right answer.
+trait Arithmetic: Ord + Sized {
+    fn from_usize(n: usize) -> Self;
+    fn from_literal(n: &BigUint) -> Option<Self>;
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+impl Arithmetic for usize {
+    fn from_usize(n: usize) -> Self {
+        n
+    }
+
+    fn from_literal(n: &BigUint) -> Option<Self> {
+        usize::try_from(n).ok()
+    }
+
+    fn checked_add(self, other: Self) -> Option<Self> {
+        usize::checked_add(self, other)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        usize::checked_mul(self, other)
+    }
+}
+
+impl Arithmetic for BigUint {
+    fn from_usize(n: usize) -> Self {
+        BigUint::from(n)
+    }
+
+    fn from_literal(n: &BigUint) -> Option<Self> {
+        Some(n.clone())
+    }
+
+    fn checked_add(self, other: Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        Some(self * other)
+    }
+}
+
+// Evaluation returns None if a literal or the arithmetic overflows. Operand counts are
+// checked by `Packet::validate` first, but anything wrong there is treated the
+// same way rather than panicking.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PacketList(Vec<Packet>);
+
+impl PacketList {
+    fn version_sum(&self) -> usize {
+        self.0.iter().map(|p| p.version_sum()).sum()
+    }
+
+    fn values<T: Arithmetic>(&self) -> Option<Vec<T>> {
+        self.0.iter().map(|p| p.evaluate()).collect()
+    }
+
+    fn sum<T: Arithmetic>(&self) -> Option<T> {
+        self.values()?.into_iter().try_fold(T::from_usize(0), T::checked_add)
+    }
+
+    fn product<T: Arithmetic>(&self) -> Option<T> {
+        self.values()?.into_iter().try_fold(T::from_usize(1), T::checked_mul)
+    }
+
+    fn min<T: Arithmetic>(&self) -> Option<T> {
+        self.values()?.into_iter().min()
+    }
+
+    fn max<T: Arithmetic>(&self) -> Option<T> {
+        self.values()?.into_iter().max()
+    }
+
+    fn compare<T, F>(&self, test: F) -> Option<T>
+        where T: Arithmetic,
+              F: Fn(&T, &T) -> bool
+    {
+        match &self.values::<T>()?[..] {
+            [first, second] => Some(T::from_usize(if test(first, second) { 1 } else { 0 })),
+            _ => None
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Packet {
+    // Literals can have any number of 4 bit groups, so they can be bigger
+    // than any machine integer.
+    Literal {
+        version: u8,
+        value: BigUint
+    },
+    Operator {
+        version: u8,
+        type_id: u8,
+        length_type: LengthType,
+        packets: PacketList
+    }
+}
+
+impl Packet {
+    fn read_literal(bits: &mut Bits, version: u8) -> Result<Self, AocError> {
+        let mut value = BigUint::default();
+        loop {
+            let last = bits.read(1)? == 0;
+            value = (value << 4u32) | BigUint::from(bits.read(4)?);
+
+            if last { break; }
+        }
+
+        Ok(Packet::Literal { version, value })
+    }
+
+    fn read_op_with_bit_len(bits: &mut Bits, version: u8, type_id: u8) -> Result<Self, AocError> {
+        let packet_length = bits.read(15)?;
+        let end = bits.position() + packet_length;
+
+        let mut packets = Vec::new();
+        while bits.position() < end {
+            packets.push(Packet::read_from_bits(bits)?);
+        }
+
+        if bits.position() != end {
+            return Err(AocError::Misc(format!(
+                "Sub-packets overran their length, ending at bit {} instead of {}",
+                bits.position(), end
+            )));
+        }
+
+        let packets = PacketList(packets);
+        let length_type = LengthType::TotalBits;
+        Ok(Packet::Operator { version, type_id, length_type, packets })
+    }
+
+    fn read_op_with_packet_count(bits: &mut Bits, version: u8, type_id: u8) -> Result<Self, AocError> {
+        let packet_count = bits.read(11)?;
+
+        let packets = (0..packet_count)
+            .map(|_| Packet::read_from_bits(bits))
+            .collect::<Result<Vec<Packet>, AocError>>()?;
+
+        let packets = PacketList(packets);
+        let length_type = LengthType::PacketCount;
+        Ok(Packet::Operator { version, type_id, length_type, packets })
+    }
+
+    fn read_from_bits(bits: &mut Bits) -> Result<Self, AocError> {
+        if bits.depth == MAX_DEPTH {
+            return Err(AocError::Misc(format!(
+                "Packets nested more than {} deep at bit {}", MAX_DEPTH, bits.position()
+            )));
+        }
+        bits.depth += 1;
+
+        let span = bits.packet_spans.len();
+        bits.packet_spans.push((bits.position(), bits.position()));
+
+        let version = bits.read(3)? as u8;
+        let type_id = bits.read(3)? as u8;
+
+        let packet = if type_id == 4 {
+            Packet::read_literal(bits, version)?
+        } else if bits.read(1)? == 0 {
+            Packet::read_op_with_bit_len(bits, version, type_id)?
+        } else {
+            Packet::read_op_with_packet_count(bits, version, type_id)?
+        };
+
+        bits.packet_spans[span].1 = bits.position();
+        bits.depth -= 1;
+        Ok(packet)
+    }
+
+    fn write_to_bits(&self, bits: &mut BitWriter) -> Result<(), AocError> {
+        match self {
+            Packet::Literal { version, value } => {
+                if *version > 7 {
+                    return Err(AocError::Misc(format!("Version {} doesn't fit in 3 bits", version)));
+                }
+                bits.write(*version as usize, 3);
+                bits.write(4, 3);
+
+                let groups = value.to_radix_be(16);
+                for (i, group) in groups.iter().enumerate() {
+                    bits.write(if i == groups.len() - 1 { 0 } else { 1 }, 1);
+                    bits.write(*group as usize, 4);
+                }
+            },
+            Packet::Operator { version, type_id, length_type, packets } => {
+                if *version > 7 {
+                    return Err(AocError::Misc(format!("Version {} doesn't fit in 3 bits", version)));
+                }
+                if *type_id > 7 || *type_id == 4 {
+                    return Err(AocError::Misc(format!("Bad operator type id {}", type_id)));
+                }
+                bits.write(*version as usize, 3);
+                bits.write(*type_id as usize, 3);
+
+                let mut sub_bits = BitWriter::new();
+                for packet in packets.0.iter() {
+                    packet.write_to_bits(&mut sub_bits)?;
+                }
+
+                // The packet's own length type if it can describe the
+                // sub-packets, otherwise the other one.
+                let fits_total_bits = sub_bits.len() < 1 << 15;
+                let fits_packet_count = packets.0.len() < 1 << 11;
+                let length_type = match length_type {
+                    LengthType::TotalBits if fits_total_bits => LengthType::TotalBits,
+                    LengthType::PacketCount if fits_packet_count => LengthType::PacketCount,
+                    _ if fits_total_bits => LengthType::TotalBits,
+                    _ if fits_packet_count => LengthType::PacketCount,
+                    _ => return Err(AocError::Misc(format!(
+                        "{} sub-packets in {} bits don't fit either length type", packets.0.len(), sub_bits.len()
+                    )))
+                };
+                match length_type {
+                    LengthType::TotalBits => {
+                        bits.write(0, 1);
+                        bits.write(sub_bits.len(), 15);
+                    },
+                    LengthType::PacketCount => {
+                        bits.write(1, 1);
+                        bits.write(packets.0.len(), 11);
+                    }
+                }
+                bits.append(&sub_bits);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn to_hex(&self) -> Result<String, AocError> {
+        let mut bits = BitWriter::new();
+        self.write_to_bits(&mut bits)?;
+        Ok(bits.to_hex())
+    }
+
+    fn version_sum(&self) -> usize {
+        match self {
+            Packet::Literal { version, .. } => *version as usize,
+            Packet::Operator { version, packets, .. } => {
+                *version as usize + packets.version_sum()
+            }
+        }
+    }
+
+    // Checks every operator has a type id it knows and the number of operands
+    // it needs.
+    fn validate(&self) -> Result<(), AocError> {
+        if let Packet::Operator { type_id, packets, .. } = self {
+            let count = packets.0.len();
+            match type_id {
+                0..=3 if count == 0 => {
+                    return Err(AocError::Misc(format!("{} packet with no operands", operator_name(*type_id))));
+                },
+                5..=7 if count != 2 => {
+                    return Err(AocError::Misc(format!(
+                        "{} packet with {} operands instead of 2", operator_name(*type_id), count
+                    )));
+                },
+                0..=3 | 5..=7 => {},
+                _ => return Err(AocError::Misc(format!("Unknown operator type id {}", type_id)))
+            }
+
+            for packet in packets.0.iter() {
+                packet.validate()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn evaluate<T: Arithmetic>(&self) -> Option<T> {
+        match self {
+            Packet::Literal { value, .. } => T::from_literal(value),
+            Packet::Operator { type_id, packets, .. } => {
+                match type_id {
+                    0 => packets.sum(),
+                    1 => packets.product(),
+                    2 => packets.min(),
+                    3 => packets.max(),
+                    5 => packets.compare(|a, b| a > b),
+                    6 => packets.compare(|a, b| a < b),
+                    7 => packets.compare(|a, b| a == b),
+                    _ => None
+                }
+            }
+        }
+    }
+
+    // Evaluates in machine integers where they're big enough, and falls back
+    // to big integers where they aren't.
+    fn value(&self) -> Result<String, AocError> {
+        self.validate()?;
+
+        if let Some(value) = self.evaluate::<usize>() {
+            Ok(value.to_string())
+        } else {
+            self.evaluate::<BigUint>()
+                .map(|value| value.to_string())
+                .ok_or_else(|| AocError::Misc("Couldn't evaluate packet".to_string()))
+        }
+    }
+
+    // The packet as an expression, with operators that have a usual infix
+    // form written that way and the rest as function calls.
+    fn expression(&self) -> String {
+        let (type_id, packets) = match self {
+            Packet::Literal { value, .. } => return value.to_string(),
+            Packet::Operator { type_id, packets, .. } => (type_id, packets)
+        };
+
+        if let Some(operator) = infix_operator(*type_id, packets.0.len()) {
+            packets.0.iter()
+                .map(|packet| if packet.is_infix() {
+                    format!("({})", packet.expression())
+                } else {
+                    packet.expression()
+                })
+                .collect::<Vec<String>>()
+                .join(operator)
+        } else {
+            let operands = packets.0.iter()
+                .map(|packet| packet.expression())
+                .collect::<Vec<String>>()
+                .join(", ");
+            match type_id {
+                0 => format!("sum({})", operands),
+                1 => format!("product({})", operands),
+                2 => format!("min({})", operands),
+                3 => format!("max({})", operands),
+                5 => format!("gt({})", operands),
+                6 => format!("lt({})", operands),
+                7 => format!("eq({})", operands),
+                _ => format!("op{}({})", type_id, operands)
+            }
+        }
+    }
+
+    fn is_infix(&self) -> bool {
+        match self {
+            Packet::Literal { .. } => false,
+            Packet::Operator { type_id, packets, .. } => infix_operator(*type_id, packets.0.len()).is_some()
+        }
+    }
+
+    // Appends one line per packet to `lines`, taking each packet's bit range
+    // from `spans` in the order they were read.
+    fn disassemble<'a, I>(&self, depth: usize, spans: &mut I, lines: &mut Vec<String>)
+        where I: Iterator<Item = &'a (usize, usize)>
+    {
+        let (start, end) = spans.next().copied().unwrap_or_default();
+        let indent = "  ".repeat(depth);
+        match self {
+            Packet::Literal { version, value } => {
+                lines.push(format!("{:>6}..{:<6} {}v{} literal {}", start, end, indent, version, value));
+            },
+            Packet::Operator { version, type_id, length_type, packets } => {
+                // Everything after the 22 bit header is sub-packets.
+                let length = match length_type {
+                    LengthType::TotalBits => format!("length type 0: {} bits", end.saturating_sub(start + 22)),
+                    LengthType::PacketCount => format!("length type 1: {} packets", packets.0.len())
+                };
+                lines.push(format!(
+                    "{:>6}..{:<6} {}v{} {} (type {}), {}",
+                    start, end, indent, version, operator_name(*type_id), type_id, length
+                ));
+                for packet in packets.0.iter() {
+                    packet.disassemble(depth + 1, spans, lines);
+                }
+            }
+        }
+    }
+}
+
+// Operators only read naturally as infix with the operand count `value`
+// expects of them.
+fn infix_operator(type_id: u8, operands: usize) -> Option<&'static str> {
+    match (type_id, operands) {
+        (0, 2..) => Some(" + "),
+        (1, 2..) => Some(" * "),
+        (5, 2) => Some(" > "),
+        (6, 2) => Some(" < "),
+        (7, 2) => Some(" == "),
+        _ => None
+    }
+}
+
+fn operator_name(type_id: u8) -> &'static str {
+    match type_id {
+        0 => "sum",
+        1 => "product",
+        2 => "minimum",
+        3 => "maximum",
+        5 => "greater than",
+        6 => "less than",
+        7 => "equal to",
+        _ => "unknown operator"
+    }
+}
+
+fn decode(input: &str) -> Result<Packet, AocError> {
+    let mut bits = Bits::new(input)?;
+    Packet::read_from_bits(&mut bits)
+}
+
+// The decoded packet as a tree, one packet per line with the range of bits it
+// was read from.
+fn disassemble(input: &str) -> Result<String, AocError> {
+    let mut bits = Bits::new(input)?;
+    let packet = Packet::read_from_bits(&mut bits)?;
+
+    let mut lines = Vec::new();
+    packet.disassemble(0, &mut bits.packet_spans.iter(), &mut lines);
+
+    let end = bits.position();
+    let padding = bits.reader.remaining();
+    lines.push(format!("{:>6}..{:<6} padding, {} bits", end, end + padding, padding));
+
+    Ok(lines.join("\n"))
+}
+
+// Both views decode the puzzle input, or a transmission given as an argument.
+fn transmission<'a>(input: &'a str, args: &'a [String]) -> &'a str {
+    args.first().map(|hex| hex.as_str()).unwrap_or(input)
+}
+
+fn render_disassembly(input: &str, args: &[String]) -> Result<Vec<String>, AocError> {
+    Ok(vec![disassemble(transmission(input, args))?])
+}
+
+fn render_expression(input: &str, args: &[String]) -> Result<Vec<String>, AocError> {
+    let packet = decode(transmission(input, args))?;
+    Ok(vec![packet.expression()])
+}
+
+pub const VIEWS: &[View] = &[
+    View {
+        name: "disassemble",
+        description: "the packet tree with each packet's bit range [HEX]",
+        render: render_disassembly,
+        args_replace_input: true
+    },
+    View {
+        name: "expression",
+        description: "the transmission as an infix expression [HEX]",
+        render: render_expression,
+        args_replace_input: true
+    }
+];
+
+// Builds a random expression of roughly `packet_count` packets. Operators
+// always get the number of operands they need, and products only multiply
+// literals, so the whole thing evaluates without overflowing.
+fn random_packet(rng: &mut Lcg, packet_count: usize) -> Packet {
+    let version = rng.below(8) as u8;
+    if packet_count <= 1 {
+        return Packet::Literal { version, value: BigUint::from(rng.below(10)) };
+    }
+
+    let mut type_id = [0, 1, 2, 3, 5, 6, 7][rng.below(7) as usize];
+    if (type_id == 1 && packet_count > 6) || ((5..=7).contains(&type_id) && packet_count < 3) {
+        type_id = 0;
+    }
+    let operands = match type_id {
+        1 => packet_count - 1,
+        5..=7 => 2,
+        _ => 2 + rng.below(4) as usize
+    }.min(packet_count - 1);
+
+    // Split the remaining packets between the operands, at least one each.
+    let mut remaining = packet_count - 1;
+    let packets = (0..operands)
+        .map(|i| {
+            let size = if i == operands - 1 {
+                remaining
+            } else {
+                1 + rng.below((remaining - (operands - i)) as u64 + 1) as usize
+            };
+            remaining -= size;
+            random_packet(rng, size)
+        })
+        .collect();
+
+    // Length type 0 can only describe sub-packets up to 32767 bits long, and
+    // literals here are at most 11 bits.
+    let length_type = if packet_count < 1000 && rng.below(2) == 0 {
+        LengthType::TotalBits
+    } else {
+        LengthType::PacketCount
+    };
+
+    Packet::Operator { version, type_id, length_type, packets: PacketList(packets) }
+}
+
+// A synthetic hex transmission of roughly `packet_count` packets, for
+// benchmarking the decoder on inputs bigger than the puzzle's.
+pub fn synthetic_transmission(packet_count: usize, seed: u64) -> Result<String, AocError> {
+    random_packet(&mut Lcg::new(seed), packet_count).to_hex()
+}
+
+pub fn parse(input: &str) -> Result<(), AocError> {
+    decode(input)?;
+
+    Ok(())
+}
+
+pub fn part_one(input: &str) -> Result<String, AocError> {
+    let packet = decode(input)?;
+
+    Ok(packet.version_sum().to_string())
+}
+
+pub fn part_two(input: &str) -> Result<String, AocError> {
+    let packet = decode(input)?;
+
+    packet.value()
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use crate::input::Lcg;
+    use num_bigint::BigUint;
+    use super::{decode, disassemble, random_packet, BitWriter, LengthType, Packet, PacketList, MAX_DEPTH};
+
+    #[test]
+    fn encodes_puzzle_examples() {
+        for hex in ["D2FE28", "38006F45291200", "EE00D40C823060"] {
+            assert_eq!(decode(hex).unwrap().to_hex().unwrap(), hex);
+        }
+    }
+
+    #[test]
+    fn prints_puzzle_examples_as_expressions() {
+        let examples = [
+            ("C200B40A82", "1 + 2"),
+            ("04005AC33890", "6 * 9"),
+            ("880086C3E88112", "min(7, 8, 9)"),
+            ("CE00C43D881120", "max(7, 8, 9)"),
+            ("D8005AC2A8F0", "5 < 15"),
+            ("F600BC2D8F", "5 > 15"),
+            ("9C005AC2F8F0", "5 == 15"),
+            ("9C0141080250320F1802104A08", "(1 + 3) == (2 * 2)")
+        ];
+        for (hex, expression) in examples {
+            assert_eq!(decode(hex).unwrap().expression(), expression);
+        }
+    }
+
+    #[test]
+    fn disassembles_with_bit_ranges() {
+        let expected = [
+            "     0..49     v1 less than (type 6), length type 0: 27 bits",
+            "    22..33       v6 literal 10",
+            "    33..49       v2 literal 20",
+            "    49..56     padding, 7 bits"
+        ];
+        assert_eq!(disassemble("38006F45291200").unwrap(), expected.join("\n"));
+    }
+
+    #[test]
+    fn reports_truncated_transmissions() {
+        let error = decode("38006F452912").unwrap_err().to_string();
+        assert!(error.contains("at bit 45 of 48"), "{}", error);
+    }
+
+    #[test]
+    fn decodes_large_transmissions() {
+        let packet = random_packet(&mut Lcg::new(16), 200_000);
+        let hex = packet.to_hex().unwrap();
+        assert!(hex.len() > 500_000);
+        assert_eq!(decode(&hex).unwrap(), packet);
+    }
+
+    // Sums of a single operand, each wrapped around the next, down to a
+    // literal 1. Written out directly, since a tree that deep is what
+    // decoding has to refuse to build.
+    fn nested_sums(depth: usize) -> String {
+        let mut bits = BitWriter::new();
+        for _ in 0..depth {
+            bits.write(0, 6);
+            bits.write(1, 1);
+            bits.write(1, 11);
+        }
+        bits.write(4, 6);
+        bits.write(1, 5);
+        bits.to_hex()
+    }
+
+    #[test]
+    fn limits_how_deep_packets_nest() {
+        let deepest = nested_sums(MAX_DEPTH - 1);
+        assert_eq!(super::part_one(&deepest).unwrap(), "0");
+        assert_eq!(super::part_two(&deepest).unwrap(), "1");
+        assert_eq!(decode(&deepest).unwrap().expression(), format!("{}1{}", "sum(".repeat(MAX_DEPTH - 1), ")".repeat(MAX_DEPTH - 1)));
+        assert!(disassemble(&deepest).is_ok());
+
+        let hex = nested_sums(20_000);
+        assert!(hex.len() > 80_000);
+        let error = super::parse(&hex).unwrap_err().to_string();
+        assert!(error.contains("nested more than 250 deep"), "{}", error);
+    }
+
+    fn literal(value: usize) -> Packet {
+        Packet::Literal { version: 0, value: BigUint::from(value) }
+    }
+
+    fn operator(type_id: u8, packets: Vec<Packet>) -> Packet {
+        Packet::Operator {
+            version: 0,
+            type_id,
+            length_type: LengthType::PacketCount,
+            packets: PacketList(packets)
+        }
+    }
+
+    #[test]
+    fn evaluates_past_usize_with_big_integers() {
+        let sum = operator(0, vec![literal(usize::MAX), literal(usize::MAX)]);
+        assert_eq!(sum.value().unwrap(), (BigUint::from(usize::MAX) * 2u32).to_string());
+
+        let product = operator(1, vec![literal(usize::MAX), literal(usize::MAX), literal(2)]);
+        assert_eq!(product.value().unwrap(), (BigUint::from(usize::MAX).pow(2) * 2u32).to_string());
+
+        // Comparisons of big results still come out as small ones.
+        let comparison = operator(5, vec![product, sum]);
+        assert_eq!(comparison.value().unwrap(), "1");
+    }
+
+    #[test]
+    fn reads_literals_past_64_bits() {
+        let big = BigUint::from(u128::MAX) * 3u32;
+        let hex = Packet::Literal { version: 5, value: big.clone() }.to_hex().unwrap();
+        assert_eq!(super::part_two(&hex).unwrap(), big.to_string());
+
+        let sum = operator(0, vec![Packet::Literal { version: 0, value: big.clone() }, literal(1)]);
+        let decoded = decode(&sum.to_hex().unwrap()).unwrap();
+        assert_eq!(decoded.value().unwrap(), (big + 1u32).to_string());
+    }
+
+    #[test]
+    fn rejects_operators_with_wrong_operand_counts() {
+        assert!(operator(2, vec![]).value().is_err());
+        assert!(operator(0, vec![operator(3, vec![])]).value().is_err());
+        assert!(operator(6, vec![literal(1)]).value().is_err());
+        assert!(operator(7, vec![literal(1), literal(1), literal(1)]).value().is_err());
+
+        // Operators with no operands still encode, so the whole way through
+        // from a transmission should be an error too.
+        let hex = operator(0, vec![]).to_hex().unwrap();
+        assert!(super::part_two(&hex).is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_type_ids() {
+        let error = operator(9, vec![literal(1)]).value().unwrap_err().to_string();
+        assert!(error.contains("Unknown operator type id 9"), "{}", error);
+    }
+
+    #[test]
+    fn falls_back_to_a_length_type_that_fits() {
+        let sum = |length_type, packets| Packet::Operator {
+            version: 0,
+            type_id: 0,
+            length_type,
+            packets: PacketList(packets)
+        };
+
+        // 1000 literals of 46 bits each are too long to count in 15 bits.
+        let long = sum(LengthType::TotalBits, vec![literal(u32::MAX as usize); 1000]);
+        let decoded = decode(&long.to_hex().unwrap()).unwrap();
+        assert!(matches!(decoded, Packet::Operator { length_type: LengthType::PacketCount, .. }));
+        assert_eq!(decoded.value().unwrap(), (u32::MAX as usize * 1000).to_string());
+
+        // 2500 literals are too many to count in 11 bits.
+        let many = sum(LengthType::PacketCount, vec![literal(1); 2500]);
+        let decoded = decode(&many.to_hex().unwrap()).unwrap();
+        assert!(matches!(decoded, Packet::Operator { length_type: LengthType::TotalBits, .. }));
+        assert_eq!(decoded.value().unwrap(), "2500");
+
+        assert!(sum(LengthType::TotalBits, vec![literal(1); 3000]).to_hex().is_err());
+    }
+
+    #[test]
+    fn rejects_unencodable_packets() {
+        let literal = Packet::Literal { version: 8, value: BigUint::from(1u32) };
+        assert!(literal.to_hex().is_err());
+
+        let operator = Packet::Operator {
+            version: 0,
+            type_id: 4,
+            length_type: LengthType::PacketCount,
+            packets: PacketList(vec![ Packet::Literal { version: 0, value: BigUint::from(1u32) } ])
+        };
+        assert!(operator.to_hex().is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn encoding_round_trips(seed in any::<u64>(), size in 1..200usize) {
+            let packet = random_packet(&mut Lcg::new(seed), size);
+            let hex = packet.to_hex().unwrap();
+            prop_assert_eq!(decode(&hex).unwrap(), packet);
+        }
+
+        #[test]
+        fn literals_round_trip(version in 0..8u8, digits in prop::collection::vec(any::<u32>(), 0..5)) {
+            let packet = Packet::Literal { version, value: BigUint::new(digits) };
+            prop_assert_eq!(decode(&packet.to_hex().unwrap()).unwrap(), packet);
+        }
+    }
+}
@@ -13,13 +13,7 @@ fn is_open(token: char) -> bool {
 }
 
 fn matching_tokens(open: char, close: char) -> bool {
-    match (open, close) {
-        ('(', ')') => true,
-        ('[', ']') => true,
-        ('{', '}') => true,
-        ('<', '>') => true,
-        _          => false
-    }
+    matches!((open, close), ('(', ')') | ('[', ']') | ('{', '}') | ('<', '>'))
 }
 
 fn corruption_token_score(token: char) -> usize {
@@ -57,7 +57,7 @@ impl FromStr for Fold {
     type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.split(" ").skip(2).next()
+        let s = s.split(" ").nth(2)
             .ok_or_else(|| AocError::Misc("Too few fold tokens".to_string()))?;
         let (axis, distance) = s.split_once("=")
             .ok_or_else(|| AocError::Misc("Bad fold string".to_string()))?;
@@ -136,28 +136,40 @@ impl Paper {
     }
 }
 
-pub fn part_one(input: &str) -> Result<String, AocError> {
+// The dotted paper and the folds to make in it.
+fn parse_manual(input: &str) -> Result<(Paper, Vec<Fold>), AocError> {
     let (points, folds) = input.split_once("\n\n")
         .ok_or_else(|| AocError::Misc("Bad input".to_string()))?;
-    let mut paper: Paper = points.parse()?;
-    let fold: Fold = folds
+    let paper = points.parse()?;
+    let folds = folds
         .lines()
-        .next()
-        .ok_or_else(|| AocError::Misc("No fold lines".to_string()))?
-        .parse()?;
+        .map(|l| l.parse())
+        .collect::<Result<Vec<Fold>, AocError>>()?;
+
+    Ok((paper, folds))
+}
+
+pub fn parse(input: &str) -> Result<(), AocError> {
+    parse_manual(input)?;
+
+    Ok(())
+}
+
+pub fn part_one(input: &str) -> Result<String, AocError> {
+    let (mut paper, folds) = parse_manual(input)?;
+    let fold = folds.first()
+        .ok_or_else(|| AocError::Misc("No fold lines".to_string()))?;
 
-    paper.fold(&fold);
+    paper.fold(fold);
 
     Ok(paper.total_points().to_string())
 }
 
 pub fn part_two(input: &str) -> Result<String, AocError> {
-    let (points, folds) = input.split_once("\n\n")
-        .ok_or_else(|| AocError::Misc("Bad input".to_string()))?;
-    let mut paper: Paper = points.parse()?;
+    let (mut paper, folds) = parse_manual(input)?;
 
-    for line in folds.lines() {
-        paper.fold(&line.parse()?);
+    for fold in folds.iter() {
+        paper.fold(fold);
     }
 
     // Add some extra spaces so my timing output lines up
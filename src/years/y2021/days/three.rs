@@ -1,15 +1,13 @@
-use std::iter::repeat;
 use crate::aoc_error::AocError;
 
 pub const NAME: &str = "Binary Diagnostic";
 
 fn find_one_counts(lines: &[&str]) -> Vec<usize> {
-    if lines.len() == 0 {
+    if lines.is_empty() {
         return Vec::new();
     }
 
-    let mut counts = Vec::with_capacity(lines[0].len());
-    counts.extend(repeat(0).take(lines[0].len()));
+    let counts = vec![0; lines[0].len()];
 
     lines
         .iter()
@@ -27,13 +25,33 @@ fn find_one_counts(lines: &[&str]) -> Vec<usize> {
         })
 }
 
-fn find_life_support_component(input: &str, gte: &str, lt: &str) -> Result<usize, AocError> {
-    let mut candidates: Vec<&str> = input.lines().collect();
+// The report's lines, checked to be binary numbers of the same width, small
+// enough for the rates to fit in a usize.
+fn parse_report(input: &str) -> Result<Vec<&str>, AocError> {
+    let lines: Vec<&str> = input.lines().collect();
+    let width = lines.first()
+        .ok_or_else(|| AocError::Misc("Empty diagnostic report".to_string()))?
+        .len();
+    if width == 0 || width >= usize::BITS as usize {
+        return Err(AocError::Misc(format!("Can't handle {} bit numbers", width)));
+    }
+
+    for line in lines.iter() {
+        if line.len() != width || !line.chars().all(|c| c == '0' || c == '1') {
+            return Err(AocError::Misc(format!("\"{}\" isn't a {} bit binary number", line, width)));
+        }
+    }
+
+    Ok(lines)
+}
+
+fn find_life_support_component(lines: &[&str], gte: &str, lt: &str) -> Result<usize, AocError> {
+    let mut candidates = lines.to_vec();
     let mut index = 0;
 
     loop {
         if candidates.len() == 1 {
-            return Ok(usize::from_str_radix(candidates[0], 2).unwrap());
+            return Ok(usize::from_str_radix(candidates[0], 2)?);
         }
 
         let bit_counts = find_one_counts(&candidates);
@@ -44,17 +62,24 @@ fn find_life_support_component(input: &str, gte: &str, lt: &str) -> Result<usize
         let keep = if bit_counts[index] >= (candidates.len() - bit_counts[index]) { gte } else { lt };
         candidates = candidates.iter()
             .filter(|c| &c[index..(index + 1)] == keep)
-            .map(|&c| c)
+            .copied()
             .collect();
 
         index += 1;
     }
 }
 
+pub fn parse(input: &str) -> Result<(), AocError> {
+    parse_report(input)?;
+
+    Ok(())
+}
+
 pub fn part_one(input: &str) -> Result<String, AocError> {
-    let total_nums = input.lines().count();
+    let lines = parse_report(input)?;
+    let total_nums = lines.len();
 
-    let set_ones = find_one_counts(&input.lines().collect::<Vec<&str>>());
+    let set_ones = find_one_counts(&lines);
 
     let mut gamma_rate = 0;
     let mut epsilon_rate = 0;
@@ -77,8 +102,9 @@ pub fn part_one(input: &str) -> Result<String, AocError> {
 }
 
 pub fn part_two(input: &str) -> Result<String, AocError> {
-    let o2 = find_life_support_component(input, "1", "0")?;
-    let co2 = find_life_support_component(input, "0", "1")?;
+    let lines = parse_report(input)?;
+    let o2 = find_life_support_component(&lines, "1", "0")?;
+    let co2 = find_life_support_component(&lines, "0", "1")?;
 
     Ok((o2 * co2).to_string())
 }
\ No newline at end of file
@@ -14,31 +14,32 @@ enum Room {
 }
 
 impl<'a> Room {
-    fn parse(s: &'a str, names: &mut HashMap<&'a str, usize>) -> Room {
+    fn parse(s: &'a str, names: &mut HashMap<&'a str, usize>) -> Result<Room, AocError> {
         lazy_static::lazy_static! {
             static ref UPPERCASE: Regex = Regex::new("[A-Z]+").unwrap();
         }
 
         if s == "start" {
-            Room::Start
+            return Ok(Room::Start);
         } else if s == "end" {
-            Room::End
-        } else if UPPERCASE.is_match(s) {
-            if let Some(n) = names.get(s) {
-                Room::Big(*n)
-            } else {
-                let n = 1 << names.len();
-                names.insert(s, n);
-                Room::Big(n)
-            }
+            return Ok(Room::End);
+        }
+
+        let n = if let Some(n) = names.get(s) {
+            *n
+        } else if names.len() >= usize::BITS as usize {
+            // Each room gets its own bit in a usize
+            return Err(AocError::Misc("Too many rooms".to_string()));
         } else {
-            if let Some(n) = names.get(s) {
-                Room::Small(*n)
-            } else {
-                let n = 1 << names.len();
-                names.insert(s, n);
-                Room::Small(n)
-            }
+            let n = 1 << names.len();
+            names.insert(s, n);
+            n
+        };
+
+        if UPPERCASE.is_match(s) {
+            Ok(Room::Big(n))
+        } else {
+            Ok(Room::Small(n))
         }
     }
 
@@ -82,11 +83,11 @@ impl RoomPath for RoomPathP1 {
         match room {
             Room::Start => false,
             Room::End => {
-                self.path.push(room.clone());
+                self.path.push(*room);
                 true
             },
             Room::Big(n) => {
-                self.path.push(room.clone());
+                self.path.push(*room);
                 self.contains |= n;
                 true
             },
@@ -94,7 +95,7 @@ impl RoomPath for RoomPathP1 {
                 if self.contains & n > 0 {
                     false
                 } else {
-                    self.path.push(room.clone());
+                    self.path.push(*room);
                     self.contains |= n;
                     true
                 }
@@ -141,25 +142,25 @@ impl RoomPath for RoomPathP2 {
         match room {
             Room::Start => false,
             Room::End => {
-                self.path.push(room.clone());
+                self.path.push(*room);
                 true
             }
             Room::Big(n) => {
-                self.path.push(room.clone());
+                self.path.push(*room);
                 self.contains |= n;
                 true
             }
             Room::Small(n) => {
                 if self.contains & n > 0 {
                     if self.doubled == 0 {
-                        self.path.push(room.clone());
+                        self.path.push(*room);
                         self.doubled = *n;
                         true
                     } else {
                         false
                     }
                 } else {
-                    self.path.push(room.clone());
+                    self.path.push(*room);
                     self.contains |= n;
                     true
                 }
@@ -203,10 +204,10 @@ impl FromStr for RoomGraph {
         for line in s.lines() {
             let (left, right) = line.split_once("-")
                 .ok_or_else(|| AocError::Misc("Bad line".to_string()))?;
-            let left = Room::parse(left, &mut names);
-            let right = Room::parse(right, &mut names);
+            let left = Room::parse(left, &mut names)?;
+            let right = Room::parse(right, &mut names)?;
 
-            graph.add_edge(left.clone(), right.clone());
+            graph.add_edge(left, right);
             graph.add_edge(right, left);
         }
 
@@ -255,6 +256,12 @@ impl RoomGraph {
     }
 }
 
+pub fn parse(input: &str) -> Result<(), AocError> {
+    input.parse::<RoomGraph>()?;
+
+    Ok(())
+}
+
 pub fn part_one(input: &str) -> Result<String, AocError> {
     let graph: RoomGraph = input.parse()?;
     let count = graph.count_paths_p1();
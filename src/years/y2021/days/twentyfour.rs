@@ -0,0 +1,1130 @@
+use std::fmt::{self, Display, Formatter};
+use std::rc::Rc;
+use std::str::FromStr;
+use crate::aoc_error::AocError;
+use crate::years::View;
+
+pub const NAME: &str = "Arithmetic Logic Unit";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Register { W, X, Y, Z }
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Value {
+    Register(Register),
+    Constant(isize)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Instruction {
+    Input(Register),
+    Add(Register, Value),
+    Multiply(Register, Value),
+    Divide(Register, Value),
+    Mod(Register, Value),
+    Equal(Register, Value)
+}
+
+#[derive(Debug)]
+pub struct ArithmeticLogicUnit {
+    instructions: Vec<Instruction>,
+    input_pointer: usize,
+    registers: [isize; 4],
+    inputs: Vec<isize>,
+    trace: Option<Vec<[isize; 4]>>
+}
+
+// Where the puzzle says the ALU stops, along with the instruction it stopped
+// on and the registers going into it.
+#[derive(Debug, PartialEq, Eq)]
+pub enum AluError {
+    DivisionByZero { index: usize, instruction: Instruction, registers: [isize; 4] },
+    NegativeModulo { index: usize, instruction: Instruction, registers: [isize; 4] },
+    Overflow { index: usize, instruction: Instruction, registers: [isize; 4] },
+    NotEnoughInputs { index: usize, instruction: Instruction, registers: [isize; 4] }
+}
+
+fn format_registers(registers: &[isize; 4]) -> String {
+    format!("w={} x={} y={} z={}", registers[0], registers[1], registers[2], registers[3])
+}
+
+impl Display for AluError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        let (problem, index, instruction, registers) = match self {
+            AluError::DivisionByZero { index, instruction, registers } => ("Division by zero", index, instruction, registers),
+            AluError::NegativeModulo { index, instruction, registers } => ("Negative modulo", index, instruction, registers),
+            AluError::Overflow { index, instruction, registers } => ("Overflow", index, instruction, registers),
+            AluError::NotEnoughInputs { index, instruction, registers } => ("Not enough inputs", index, instruction, registers)
+        };
+        write!(f, "{} at instruction {} \"{}\" with {}", problem, index, instruction, format_registers(registers))
+    }
+}
+
+impl From<AluError> for AocError {
+    fn from(error: AluError) -> Self {
+        AocError::Misc(error.to_string())
+    }
+}
+
+impl FromStr for Register {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "w" => Ok(Register::W),
+            "x" => Ok(Register::X),
+            "y" => Ok(Register::Y),
+            "z" => Ok(Register::Z),
+            _ => Err(AocError::Misc(format!("Bad register name \"{}\"", s)))
+        }
+    }
+}
+
+impl FromStr for Value {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(value) = s.parse() {
+            Ok(Value::Constant(value))
+        } else {
+            let register: Register = s.parse()?;
+            Ok(Value::Register(register))
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens: Vec<&str> = s.split(' ').collect();
+        let expected_tokens = if tokens[0] == "inp" { 2 } else { 3 };
+        if tokens.len() != expected_tokens {
+            Err(AocError::Misc(format!("Wrong number of tokens in \"{}\"", s)))
+        } else {
+            match tokens[0] {
+                "inp" => Ok(Instruction::Input(tokens[1].parse()?)),
+                "add" => Ok(Instruction::Add(tokens[1].parse()?, tokens[2].parse()?)),
+                "mul" => Ok(Instruction::Multiply(tokens[1].parse()?, tokens[2].parse()?)),
+                "div" => Ok(Instruction::Divide(tokens[1].parse()?, tokens[2].parse()?)),
+                "mod" => Ok(Instruction::Mod(tokens[1].parse()?, tokens[2].parse()?)),
+                "eql" => Ok(Instruction::Equal(tokens[1].parse()?, tokens[2].parse()?)),
+                _ => Err(AocError::Misc(format!("Bad opcode \"{}\"", tokens[0])))
+            }
+        }
+    }
+}
+
+impl Display for Register {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        let name = match self {
+            Register::W => "w",
+            Register::X => "x",
+            Register::Y => "y",
+            Register::Z => "z"
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            Value::Register(register) => write!(f, "{}", register),
+            Value::Constant(n) => write!(f, "{}", n)
+        }
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            Instruction::Input(register) => write!(f, "inp {}", register),
+            Instruction::Add(register, value) => write!(f, "add {} {}", register, value),
+            Instruction::Multiply(register, value) => write!(f, "mul {} {}", register, value),
+            Instruction::Divide(register, value) => write!(f, "div {} {}", register, value),
+            Instruction::Mod(register, value) => write!(f, "mod {} {}", register, value),
+            Instruction::Equal(register, value) => write!(f, "eql {} {}", register, value)
+        }
+    }
+}
+
+fn parse_program(program: &str) -> Result<Vec<Instruction>, AocError> {
+    program.lines()
+        .map(|line| line.parse())
+        .collect()
+}
+
+impl ArithmeticLogicUnit {
+    pub fn new(program: &str, input: Vec<isize>) -> Result<Self, AocError> {
+        let instructions = parse_program(program)?;
+        Ok(ArithmeticLogicUnit {
+            instructions,
+            input_pointer: 0,
+            registers: [0, 0, 0, 0],
+            inputs: input,
+            trace: None
+        })
+    }
+}
+
+impl Register {
+    fn index(&self) -> usize {
+        match self {
+            Register::W => 0,
+            Register::X => 1,
+            Register::Y => 2,
+            Register::Z => 3
+        }
+    }
+}
+
+impl ArithmeticLogicUnit {
+    // Copies the new inputs into the old ones' buffer, so running lots of
+    // inputs through one ALU doesn't allocate for each.
+    pub fn reset(&mut self, input: &[isize]) {
+        self.registers = [0, 0, 0, 0];
+        self.input_pointer = 0;
+        self.inputs.clear();
+        self.inputs.extend_from_slice(input);
+        if let Some(trace) = &mut self.trace {
+            trace.clear();
+        }
+    }
+
+    // Opts in to recording the registers after every instruction, which
+    // slows things down but shows where a program goes wrong.
+    pub fn enable_trace(&mut self) {
+        self.trace.get_or_insert_with(Vec::new);
+    }
+
+    pub fn trace(&self) -> Option<&[[isize; 4]]> {
+        self.trace.as_deref()
+    }
+
+    fn value(&self, value: &Value) -> isize {
+        match value {
+            Value::Constant(value) => *value,
+            Value::Register(register) => self.registers[register.index()]
+        }
+    }
+
+    pub fn registers(&self) -> [isize; 4] {
+        self.registers
+    }
+
+    fn step(&mut self, index: usize) -> Result<(), AluError> {
+        let instruction = self.instructions[index];
+        let registers = self.registers;
+        let (register, result) = match instruction {
+            Instruction::Input(register) => {
+                let Some(&input) = self.inputs.get(self.input_pointer) else {
+                    return Err(AluError::NotEnoughInputs { index, instruction, registers });
+                };
+                self.input_pointer += 1;
+                (register, input)
+            },
+            Instruction::Add(register, value) => {
+                let result = registers[register.index()].checked_add(self.value(&value))
+                    .ok_or(AluError::Overflow { index, instruction, registers })?;
+                (register, result)
+            },
+            Instruction::Multiply(register, value) => {
+                let result = registers[register.index()].checked_mul(self.value(&value))
+                    .ok_or(AluError::Overflow { index, instruction, registers })?;
+                (register, result)
+            },
+            Instruction::Divide(register, value) => {
+                let divisor = self.value(&value);
+                if divisor == 0 {
+                    return Err(AluError::DivisionByZero { index, instruction, registers });
+                }
+                let result = registers[register.index()].checked_div(divisor)
+                    .ok_or(AluError::Overflow { index, instruction, registers })?;
+                (register, result)
+            },
+            Instruction::Mod(register, value) => {
+                let (dividend, divisor) = (registers[register.index()], self.value(&value));
+                if divisor == 0 {
+                    return Err(AluError::DivisionByZero { index, instruction, registers });
+                }
+                if dividend < 0 || divisor < 0 {
+                    return Err(AluError::NegativeModulo { index, instruction, registers });
+                }
+                (register, dividend % divisor)
+            },
+            Instruction::Equal(register, value) => {
+                (register, (registers[register.index()] == self.value(&value)) as isize)
+            }
+        };
+
+        self.registers[register.index()] = result;
+        Ok(())
+    }
+
+    pub fn run(&mut self) -> Result<(), AluError> {
+        for index in 0..self.instructions.len() {
+            self.step(index)?;
+            if let Some(trace) = &mut self.trace {
+                trace.push(self.registers);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// The program flattened into bytecode for running over lots of inputs, with
+// registers as plain indices and constant operands split out into their own
+// opcodes, so each step is a single match.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Op {
+    Input(usize),
+    Set(usize, isize),
+    Copy(usize, usize),
+    AddConstant(usize, isize),
+    AddRegister(usize, usize),
+    MultiplyConstant(usize, isize),
+    MultiplyRegister(usize, usize),
+    DivideConstant(usize, isize),
+    DivideRegister(usize, usize),
+    ModConstant(usize, isize),
+    ModRegister(usize, usize),
+    EqualConstant(usize, isize),
+    EqualRegister(usize, usize)
+}
+
+impl Op {
+    fn new(operator: Operator, register: usize, value: Value) -> Self {
+        match (operator, value) {
+            (Operator::Add, Value::Constant(n)) => Op::AddConstant(register, n),
+            (Operator::Add, Value::Register(other)) => Op::AddRegister(register, other.index()),
+            (Operator::Multiply, Value::Constant(n)) => Op::MultiplyConstant(register, n),
+            (Operator::Multiply, Value::Register(other)) => Op::MultiplyRegister(register, other.index()),
+            (Operator::Divide, Value::Constant(n)) => Op::DivideConstant(register, n),
+            (Operator::Divide, Value::Register(other)) => Op::DivideRegister(register, other.index()),
+            (Operator::Mod, Value::Constant(n)) => Op::ModConstant(register, n),
+            (Operator::Mod, Value::Register(other)) => Op::ModRegister(register, other.index()),
+            (Operator::Equal, Value::Constant(n)) => Op::EqualConstant(register, n),
+            (Operator::Equal, Value::Register(other)) => Op::EqualRegister(register, other.index())
+        }
+    }
+
+    // The register written, whether its old value is read, and any other
+    // register read.
+    fn registers(&self) -> (usize, bool, Option<usize>) {
+        match *self {
+            Op::Input(r) | Op::Set(r, _) => (r, false, None),
+            Op::Copy(r, other) => (r, false, Some(other)),
+            Op::AddConstant(r, _) | Op::MultiplyConstant(r, _) | Op::DivideConstant(r, _) |
+            Op::ModConstant(r, _) | Op::EqualConstant(r, _) => (r, true, None),
+            Op::AddRegister(r, other) | Op::MultiplyRegister(r, other) | Op::DivideRegister(r, other) |
+            Op::ModRegister(r, other) | Op::EqualRegister(r, other) => (r, true, Some(other))
+        }
+    }
+
+    // Inputs move the input pointer along and arithmetic can overflow or
+    // divide by zero, so none of them can be dropped just because nothing
+    // reads what they write.
+    fn has_side_effects(&self) -> bool {
+        !matches!(self, Op::Set(..) | Op::Copy(..) | Op::EqualConstant(..) | Op::EqualRegister(..))
+    }
+}
+
+// Alongside each op is the index of the instruction it came from, so
+// failures can be reported against the program as written.
+#[derive(Debug)]
+pub struct CompiledProgram {
+    instructions: Vec<Instruction>,
+    ops: Vec<Op>,
+    sources: Vec<usize>
+}
+
+impl CompiledProgram {
+    pub fn compile(program: &str) -> Result<Self, AocError> {
+        let instructions = parse_program(program)?;
+        let ops = CompiledProgram::propagate_constants(&instructions);
+        let (sources, ops) = CompiledProgram::eliminate_dead_stores(ops).into_iter().unzip();
+        Ok(CompiledProgram { instructions, ops, sources })
+    }
+
+    // Works out every register value that doesn't depend on the inputs at
+    // compile time, only writing it into the register once something needs
+    // it there.
+    fn propagate_constants(instructions: &[Instruction]) -> Vec<(usize, Op)> {
+        let mut known: [Option<isize>; 4] = [Some(0); 4];
+        let mut written = [false; 4];
+        let mut ops = Vec::new();
+
+        for (index, instruction) in instructions.iter().enumerate() {
+            let (register, operator, value) = match instruction {
+                Instruction::Input(register) => {
+                    ops.push((index, Op::Input(register.index())));
+                    known[register.index()] = None;
+                    written[register.index()] = true;
+                    continue;
+                },
+                Instruction::Add(register, value) => (register.index(), Operator::Add, *value),
+                Instruction::Multiply(register, value) => (register.index(), Operator::Multiply, *value),
+                Instruction::Divide(register, value) => (register.index(), Operator::Divide, *value),
+                Instruction::Mod(register, value) => (register.index(), Operator::Mod, *value),
+                Instruction::Equal(register, value) => (register.index(), Operator::Equal, *value)
+            };
+
+            let value = match value {
+                Value::Register(other) => known[other.index()].map_or(value, Value::Constant),
+                constant => constant
+            };
+
+            let folded = match (known[register], value, operator) {
+                (Some(a), Value::Constant(b), _) => operator.apply(a, b).map(Some),
+                (_, Value::Constant(0), Operator::Add) |
+                (_, Value::Constant(1), Operator::Multiply) |
+                (_, Value::Constant(1), Operator::Divide) => Some(known[register]),
+                (_, Value::Constant(0), Operator::Multiply) |
+                (Some(0), _, Operator::Multiply) => Some(Some(0)),
+                _ => None
+            };
+            if let Some(result) = folded {
+                known[register] = result;
+                continue;
+            }
+
+            match (known[register], value, operator) {
+                (Some(0), Value::Register(other), Operator::Add) |
+                (Some(1), Value::Register(other), Operator::Multiply) => {
+                    ops.push((index, Op::Copy(register, other.index())));
+                },
+                (a, value, operator) => {
+                    if let Some(a) = a {
+                        ops.push((index, Op::Set(register, a)));
+                    }
+                    ops.push((index, Op::new(operator, register, value)));
+                }
+            }
+            known[register] = None;
+            written[register] = true;
+        }
+
+        let end = instructions.len().saturating_sub(1);
+        for register in 0..4 {
+            if let Some(n) = known[register].filter(|n| written[register] || *n != 0) {
+                ops.push((end, Op::Set(register, n)));
+            }
+        }
+
+        ops
+    }
+
+    // Works backwards from the end, where all four registers count, dropping
+    // anything that writes a register that's overwritten before it's read.
+    fn eliminate_dead_stores(ops: Vec<(usize, Op)>) -> Vec<(usize, Op)> {
+        let mut live = [true; 4];
+        let mut kept: Vec<(usize, Op)> = ops.into_iter()
+            .rev()
+            .filter(|(_, op)| {
+                let (register, reads_register, other) = op.registers();
+                if !live[register] && !op.has_side_effects() {
+                    return false;
+                }
+
+                live[register] = reads_register;
+                if let Some(other) = other {
+                    live[other] = true;
+                }
+                true
+            })
+            .collect();
+        kept.reverse();
+        kept
+    }
+
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    pub fn run(&self, inputs: &[isize]) -> Result<[isize; 4], AluError> {
+        let mut registers = [0; 4];
+        let mut remaining = inputs.iter();
+
+        for (position, op) in self.ops.iter().enumerate() {
+            let (r, result) = match *op {
+                Op::Input(r) => (r, remaining.next().copied()),
+                Op::Set(r, n) => (r, Some(n)),
+                Op::Copy(r, other) => (r, Some(registers[other])),
+                Op::AddConstant(r, n) => (r, registers[r].checked_add(n)),
+                Op::AddRegister(r, other) => (r, registers[r].checked_add(registers[other])),
+                Op::MultiplyConstant(r, n) => (r, registers[r].checked_mul(n)),
+                Op::MultiplyRegister(r, other) => (r, registers[r].checked_mul(registers[other])),
+                Op::DivideConstant(r, n) => (r, registers[r].checked_div(n)),
+                Op::DivideRegister(r, other) => (r, registers[r].checked_div(registers[other])),
+                Op::ModConstant(r, n) => (r, Operator::Mod.apply(registers[r], n)),
+                Op::ModRegister(r, other) => (r, Operator::Mod.apply(registers[r], registers[other])),
+                Op::EqualConstant(r, n) => (r, Some((registers[r] == n) as isize)),
+                Op::EqualRegister(r, other) => (r, Some((registers[r] == registers[other]) as isize))
+            };
+            registers[r] = result.ok_or_else(|| self.failure(position, inputs))?;
+        }
+
+        Ok(registers)
+    }
+
+    // The compiled registers lag behind the real ones wherever constants
+    // haven't been written yet, so this replays the program up to the op's
+    // instruction to say exactly what the ALU would have.
+    fn failure(&self, position: usize, inputs: &[isize]) -> AluError {
+        let mut alu = ArithmeticLogicUnit {
+            instructions: self.instructions[..=self.sources[position]].to_vec(),
+            input_pointer: 0,
+            registers: [0, 0, 0, 0],
+            inputs: inputs.to_vec(),
+            trace: None
+        };
+        match alu.run() {
+            Err(error) => error,
+            Ok(()) => unreachable!("Compiled op {:?} failed where the ALU doesn't", self.ops[position])
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Operator { Add, Multiply, Divide, Mod, Equal }
+
+impl Operator {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Operator::Add => "+",
+            Operator::Multiply => "*",
+            Operator::Divide => "/",
+            Operator::Mod => "%",
+            Operator::Equal => "=="
+        }
+    }
+
+    // Applies the operator to known values, or None where the puzzle leaves
+    // it undefined.
+    fn apply(&self, a: isize, b: isize) -> Option<isize> {
+        match self {
+            Operator::Add => a.checked_add(b),
+            Operator::Multiply => a.checked_mul(b),
+            Operator::Divide => a.checked_div(b),
+            Operator::Mod => if a < 0 || b <= 0 { None } else { Some(a % b) },
+            Operator::Equal => Some(if a == b { 1 } else { 0 })
+        }
+    }
+}
+
+// A register's value in terms of the inputs, which are digits from 1 to 9.
+// Operations carry the range of values they can take, worked out when they're
+// built, which is what lets `eql` of things that can never be equal fold away.
+#[derive(Debug, PartialEq, Eq)]
+enum Expression {
+    Constant(isize),
+    Input(usize),
+    Operation {
+        operator: Operator,
+        left: Rc<Expression>,
+        right: Rc<Expression>,
+        range: (isize, isize)
+    }
+}
+
+impl Display for Expression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            Expression::Constant(n) => write!(f, "{}", n),
+            Expression::Input(i) => write!(f, "d{}", i + 1),
+            Expression::Operation { operator: Operator::Add, left, right, .. }
+                if right.constant().is_some_and(|n| n < 0) => {
+                let n = right.constant().unwrap();
+                match **left {
+                    Expression::Operation { .. } => write!(f, "({}) - {}", left, n.unsigned_abs()),
+                    _ => write!(f, "{} - {}", left, n.unsigned_abs())
+                }
+            },
+            Expression::Operation { operator, left, right, .. } => {
+                let side = |expression: &Expression| match expression {
+                    Expression::Operation { .. } => format!("({})", expression),
+                    _ => expression.to_string()
+                };
+                write!(f, "{} {} {}", side(left), operator.symbol(), side(right))
+            }
+        }
+    }
+}
+
+impl Expression {
+    fn range(&self) -> (isize, isize) {
+        match self {
+            Expression::Constant(n) => (*n, *n),
+            Expression::Input(_) => (1, 9),
+            Expression::Operation { range, .. } => *range
+        }
+    }
+
+    fn constant(&self) -> Option<isize> {
+        match self {
+            Expression::Constant(n) => Some(*n),
+            _ => None
+        }
+    }
+
+    fn within(&self, low: isize, high: isize) -> bool {
+        let (min, max) = self.range();
+        min >= low && max <= high
+    }
+
+    #[cfg(test)]
+    fn evaluate(&self, inputs: &[isize]) -> Option<isize> {
+        match self {
+            Expression::Constant(n) => Some(*n),
+            Expression::Input(i) => inputs.get(*i).copied(),
+            Expression::Operation { operator, left, right, .. } => {
+                operator.apply(left.evaluate(inputs)?, right.evaluate(inputs)?)
+            }
+        }
+    }
+
+    // The range an operation's result can fall in, saturating rather than
+    // overflowing, and erring wide where it can't tell.
+    fn range_of(operator: Operator, left: &Expression, right: &Expression) -> (isize, isize) {
+        let (a_min, a_max) = left.range();
+        let (b_min, b_max) = right.range();
+        let magnitude = a_min.saturating_abs().max(a_max.saturating_abs());
+
+        match operator {
+            Operator::Add => (a_min.saturating_add(b_min), a_max.saturating_add(b_max)),
+            Operator::Multiply => {
+                let products = [
+                    a_min.saturating_mul(b_min), a_min.saturating_mul(b_max),
+                    a_max.saturating_mul(b_min), a_max.saturating_mul(b_max)
+                ];
+                (*products.iter().min().unwrap(), *products.iter().max().unwrap())
+            },
+            Operator::Divide if b_min > 0 => {
+                let quotients = [a_min / b_min, a_min / b_max, a_max / b_min, a_max / b_max];
+                (*quotients.iter().min().unwrap(), *quotients.iter().max().unwrap())
+            },
+            Operator::Divide => (-magnitude, magnitude),
+            Operator::Mod => (0, a_max.max(0).min(b_max.saturating_sub(1).max(0))),
+            Operator::Equal => (0, 1)
+        }
+    }
+
+    // Builds `left operator right`, simplified as far as it can be.
+    fn operation(operator: Operator, left: Rc<Expression>, right: Rc<Expression>) -> Rc<Expression> {
+        use Operator::*;
+
+        if let (Some(a), Some(b)) = (left.constant(), right.constant()) {
+            if let Some(n) = operator.apply(a, b) {
+                return Rc::new(Expression::Constant(n));
+            }
+        }
+
+        let (a, b) = (left.constant(), right.constant());
+        match operator {
+            Add if a == Some(0) => return right,
+            Add if b == Some(0) => return left,
+            Multiply if a == Some(0) || b == Some(0) => return Rc::new(Expression::Constant(0)),
+            Multiply if b == Some(1) => return left,
+            Multiply if a == Some(1) => return right,
+            Divide if b == Some(1) => return left,
+            Equal if left == right => return Rc::new(Expression::Constant(1)),
+            Equal => {
+                let ((a_min, a_max), (b_min, b_max)) = (left.range(), right.range());
+                if a_max < b_min || b_max < a_min {
+                    return Rc::new(Expression::Constant(0));
+                }
+            },
+            _ => {}
+        }
+
+        if let Some(divisor) = b.filter(|divisor| *divisor > 0) {
+            if let Divide | Mod = operator {
+                // Small enough to be all remainder.
+                if left.within(0, divisor - 1) {
+                    return if operator == Divide { Rc::new(Expression::Constant(0)) } else { left };
+                }
+
+                // (x * d + y) / d is x, and (x * d + y) % d is y, when y is
+                // small enough to be all remainder. This is how MONAD pops a
+                // base 26 digit off z.
+                if let Expression::Operation { operator: Add, left: high, right: low, .. } = &*left {
+                    if let Expression::Operation { operator: Multiply, left: x, right: d, .. } = &**high {
+                        if d.constant() == Some(divisor) && x.within(0, isize::MAX) && low.within(0, divisor - 1) {
+                            return if operator == Divide { x.clone() } else { low.clone() };
+                        }
+                    }
+                }
+            }
+        }
+
+        // Gathers constants added on to the same expression.
+        if let (Add, Some(c)) = (operator, b) {
+            if let Expression::Operation { operator: Add, left: x, right: inner, .. } = &*left {
+                if let Some(n) = inner.constant().and_then(|n| n.checked_add(c)) {
+                    return Expression::operation(Add, x.clone(), Rc::new(Expression::Constant(n)));
+                }
+            }
+        }
+
+        let range = Expression::range_of(operator, &left, &right);
+        Rc::new(Expression::Operation { operator, left, right, range })
+    }
+}
+
+impl ArithmeticLogicUnit {
+    // Runs the program on unknown inputs, returning what's in z right before
+    // each input after the first, and at the end.
+    fn run_symbolic(&self) -> Vec<Rc<Expression>> {
+        let zero = Rc::new(Expression::Constant(0));
+        let mut registers = [zero.clone(), zero.clone(), zero.clone(), zero];
+        let mut inputs = 0;
+        let mut z_per_block = Vec::new();
+
+        for instruction in &self.instructions {
+            let (register, operator, value) = match instruction {
+                Instruction::Input(register) => {
+                    if inputs > 0 {
+                        z_per_block.push(registers[Register::Z.index()].clone());
+                    }
+                    registers[register.index()] = Rc::new(Expression::Input(inputs));
+                    inputs += 1;
+                    continue;
+                },
+                Instruction::Add(register, value) => (register, Operator::Add, value),
+                Instruction::Multiply(register, value) => (register, Operator::Multiply, value),
+                Instruction::Divide(register, value) => (register, Operator::Divide, value),
+                Instruction::Mod(register, value) => (register, Operator::Mod, value),
+                Instruction::Equal(register, value) => (register, Operator::Equal, value)
+            };
+
+            let right = match value {
+                Value::Constant(n) => Rc::new(Expression::Constant(*n)),
+                Value::Register(other) => registers[other.index()].clone()
+            };
+            let left = registers[register.index()].clone();
+            registers[register.index()] = Expression::operation(operator, left, right);
+        }
+
+        z_per_block.push(registers[Register::Z.index()].clone());
+        z_per_block
+    }
+}
+
+// MONAD is 14 copies of the same block of instructions, one per digit, that
+// only differ in three constants. Between them they treat z as a stack of
+// base 26 numbers: blocks that divide z by 1 push the digit plus an offset,
+// and blocks that divide by 26 pop the top value and check it against the
+// digit, pushing it back on if they don't match. With as many pops as pushes,
+// z only ends at 0 if every check matches.
+fn monad_block(divisor: isize, check: isize, offset: isize) -> [Instruction; 18] {
+    use Instruction::*;
+    use Register::*;
+    use Value::Constant;
+
+    [
+        Input(W),
+        Multiply(X, Constant(0)),
+        Add(X, Value::Register(Z)),
+        Mod(X, Constant(26)),
+        Divide(Z, Constant(divisor)),
+        Add(X, Constant(check)),
+        Equal(X, Value::Register(W)),
+        Equal(X, Constant(0)),
+        Multiply(Y, Constant(0)),
+        Add(Y, Constant(25)),
+        Multiply(Y, Value::Register(X)),
+        Add(Y, Constant(1)),
+        Multiply(Z, Value::Register(Y)),
+        Multiply(Y, Constant(0)),
+        Add(Y, Value::Register(W)),
+        Add(Y, Constant(offset)),
+        Multiply(Y, Value::Register(X)),
+        Add(Z, Value::Register(Y))
+    ]
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Block {
+    Push { offset: isize },
+    Pop { check: isize }
+}
+
+fn constant(value: &Value) -> Option<isize> {
+    match value {
+        Value::Constant(n) => Some(*n),
+        Value::Register(_) => None
+    }
+}
+
+// Reads each block's constants out of the program, checking the rest of it
+// matches the MONAD template.
+fn analyze(instructions: &[Instruction]) -> Result<Vec<Block>, AocError> {
+    if instructions.len() != 14 * 18 {
+        return Err(AocError::Misc(format!(
+            "Expected 14 blocks of 18 instructions, got {} instructions", instructions.len()
+        )));
+    }
+
+    instructions.chunks(18)
+        .enumerate()
+        .map(|(i, block)| {
+            let parameters = match (block[4], block[5], block[15]) {
+                (Instruction::Divide(_, divisor), Instruction::Add(_, check), Instruction::Add(_, offset)) =>
+                    constant(&divisor).zip(constant(&check)).zip(constant(&offset)),
+                _ => None
+            };
+            let ((divisor, check), offset) = parameters
+                .filter(|((divisor, check), offset)| block == monad_block(*divisor, *check, *offset))
+                .ok_or_else(|| AocError::Misc(format!("Block {} doesn't look like part of MONAD", i + 1)))?;
+
+            match divisor {
+                // A check over 9 can never match a digit, so these always push.
+                1 if check > 9 => Ok(Block::Push { offset }),
+                26 => Ok(Block::Pop { check }),
+                _ => Err(AocError::Misc(format!("Block {} neither pushes nor pops", i + 1)))
+            }
+        })
+        .collect()
+}
+
+// Pairs each pop with the push it undoes, as (push digit, pop digit,
+// difference), where z only returns to 0 if every pop digit is its push digit
+// plus the difference.
+fn constraints(blocks: &[Block]) -> Result<Vec<(usize, usize, isize)>, AocError> {
+    let mut stack = Vec::new();
+    let mut constraints = Vec::new();
+
+    for (i, block) in blocks.iter().enumerate() {
+        match block {
+            Block::Push { offset } => stack.push((i, *offset)),
+            Block::Pop { check } => {
+                let (pushed, offset) = stack.pop()
+                    .ok_or_else(|| AocError::Misc(format!("Block {} pops from an empty z", i + 1)))?;
+                constraints.push((pushed, i, offset + check));
+            }
+        }
+    }
+
+    if !stack.is_empty() {
+        return Err(AocError::Misc("z can never get back to 0".to_string()));
+    }
+
+    Ok(constraints)
+}
+
+// The largest or smallest digits meeting every constraint.
+fn model_number(constraints: &[(usize, usize, isize)], largest: bool) -> Result<Vec<isize>, AocError> {
+    let mut digits = vec![0; constraints.len() * 2];
+    for &(first, second, difference) in constraints {
+        if difference.abs() > 8 {
+            return Err(AocError::Misc(format!(
+                "No digits {} apart for digits {} and {}", difference, first + 1, second + 1
+            )));
+        }
+
+        digits[first] = if largest { 9.min(9 - difference) } else { 1.max(1 - difference) };
+        digits[second] = digits[first] + difference;
+    }
+
+    Ok(digits)
+}
+
+fn find_model_number(program: &str, largest: bool) -> Result<String, AocError> {
+    let blocks = analyze(&parse_program(program)?)?;
+    let digits = model_number(&constraints(&blocks)?, largest)?;
+
+    // Double check it on the ALU itself.
+    let mut alu = ArithmeticLogicUnit::new(program, digits.clone())?;
+    alu.run()?;
+    if alu.registers[Register::Z.index()] != 0 {
+        return Err(AocError::Misc("MONAD rejected the model number it was solved for".to_string()));
+    }
+
+    Ok(digits.iter().map(|digit| digit.to_string()).collect())
+}
+
+pub fn parse(input: &str) -> Result<(), AocError> {
+    parse_program(input)?;
+
+    Ok(())
+}
+
+pub fn part_one(input: &str) -> Result<String, AocError> {
+    find_model_number(input, true)
+}
+
+pub fn part_two(input: &str) -> Result<String, AocError> {
+    find_model_number(input, false)
+}
+
+fn render_symbolic(input: &str, args: &[String]) -> Result<Vec<String>, AocError> {
+    if !args.is_empty() {
+        return Err(AocError::Misc("The symbolic view doesn't take any arguments".to_string()));
+    }
+
+    let alu = ArithmeticLogicUnit::new(input, Vec::new())?;
+    Ok(alu.run_symbolic()
+        .iter()
+        .enumerate()
+        .map(|(i, z)| format!("After block {}:\nz = {}", i + 1, z))
+        .collect())
+}
+
+// Shows the registers after each instruction, a frame per input, running on
+// the given digits or all 9s.
+fn render_trace(input: &str, args: &[String]) -> Result<Vec<String>, AocError> {
+    let digits: Vec<isize> = match args.first() {
+        Some(digits) => digits.chars()
+            .map(|c| c.to_digit(10)
+                .map(|digit| digit as isize)
+                .ok_or_else(|| AocError::Misc(format!("Bad digit '{}' in \"{}\"", c, digits))))
+            .collect::<Result<_, _>>()?,
+        None => vec![9; 14]
+    };
+
+    let mut alu = ArithmeticLogicUnit::new(input, digits)?;
+    alu.enable_trace();
+    let result = alu.run();
+
+    let mut frames: Vec<String> = Vec::new();
+    for (index, (instruction, registers)) in alu.instructions.iter().zip(alu.trace().unwrap()).enumerate() {
+        if index == 0 || matches!(instruction, Instruction::Input(_)) {
+            frames.push(String::new());
+        } else {
+            frames.last_mut().unwrap().push('\n');
+        }
+        frames.last_mut().unwrap()
+            .push_str(&format!("{:>4}  {:12}{}", index, instruction.to_string(), format_registers(registers)));
+    }
+    if let Err(error) = result {
+        frames.push(error.to_string());
+    }
+
+    Ok(frames)
+}
+
+pub const VIEWS: &[View] = &[
+    View {
+        name: "symbolic",
+        description: "z in terms of the digits after each input block",
+        render: render_symbolic,
+        args_replace_input: false
+    },
+    View {
+        name: "trace",
+        description: "the registers after every instruction [DIGITS]",
+        render: render_trace,
+        args_replace_input: false
+    }
+];
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+    use crate::input::Lcg;
+    use super::{
+        part_one, part_two, AluError, ArithmeticLogicUnit, CompiledProgram, Expression, Instruction, Op, Operator,
+        Register, Value
+    };
+
+    fn block(divisor: isize, check: isize, offset: isize) -> String {
+        format!(
+            "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\nmul y 0\nadd y 25\n\
+             mul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {}\nmul y x\nadd z y",
+            divisor, check, offset
+        )
+    }
+
+    fn program(blocks: &[(isize, isize, isize)]) -> String {
+        blocks.iter()
+            .map(|&(divisor, check, offset)| block(divisor, check, offset))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn solves_nested_pushes_and_pops() {
+        // Pairs up as (1, 14), (2, 3), (4, 13), (5, 6), (7, 12), (8, 11), (9, 10).
+        let monad = program(&[
+            (1, 11, 1), (1, 12, 2), (26, -4, 0), (1, 10, 5), (1, 13, 0), (26, 0, 0), (1, 14, 3),
+            (1, 10, 0), (1, 11, 7), (26, -8, 0), (26, 0, 0), (26, -5, 0), (26, -7, 0), (26, -1, 0)
+        ]);
+        assert_eq!(part_one(&monad).unwrap(), "99799999989779");
+        assert_eq!(part_two(&monad).unwrap(), "13131131211111");
+    }
+
+    #[test]
+    fn rejects_programs_it_cant_solve() {
+        // Digits can't be 9 apart.
+        let mut blocks = vec![(1, 10, 0), (26, 9, 0)];
+        blocks.extend([(1, 10, 0), (26, 0, 0)].repeat(6));
+        assert!(part_one(&program(&blocks)).is_err());
+
+        // More pushes than pops leaves z above 0.
+        let blocks = [(1, 10, 0)].repeat(14);
+        assert!(part_one(&program(&blocks)).is_err());
+
+        // Not MONAD at all.
+        assert!(part_one("inp w\nadd z w").is_err());
+    }
+
+    #[test]
+    fn folds_monad_down_to_base_26_digits() {
+        let monad = program(&[(1, 12, 12), (1, 11, 8), (26, -8, 3)]);
+        let alu = ArithmeticLogicUnit::new(&monad, Vec::new()).unwrap();
+        let z = alu.run_symbolic();
+        assert_eq!(z.len(), 3);
+        assert_eq!(z[0].to_string(), "d1 + 12");
+        assert_eq!(z[1].to_string(), "((d1 + 12) * 26) + (d2 + 8)");
+        // Popping d2 + 8 back off leaves a check on d2 - d3 instead of a
+        // tangle of mods and divides.
+        assert!(!z[2].to_string().contains('%'), "{}", z[2]);
+    }
+
+    #[test]
+    fn agrees_with_the_alu() {
+        let monad = program(&[
+            (1, 12, 12), (1, 11, 8), (1, 13, 7), (26, -11, 5), (1, 10, 3), (26, -3, 9), (26, -7, 2)
+        ]);
+        let z = ArithmeticLogicUnit::new(&monad, Vec::new()).unwrap().run_symbolic().pop().unwrap();
+        for digits in [[1, 1, 1, 1, 1, 1, 1], [9, 9, 9, 9, 9, 9, 9], [3, 1, 4, 1, 5, 9, 2], [5, 8, 9, 5, 2, 9, 4]] {
+            let mut alu = ArithmeticLogicUnit::new(&monad, digits.to_vec()).unwrap();
+            alu.run().unwrap();
+            assert_eq!(z.evaluate(&digits), Some(alu.registers[3]), "{:?}", digits);
+        }
+    }
+
+    #[test]
+    fn simplifies_using_ranges() {
+        let digit = Rc::new(Expression::Input(0));
+        let constant = |n| Rc::new(Expression::Constant(n));
+        let equal = Expression::operation(Operator::Equal, digit.clone(), constant(10));
+        assert_eq!(*equal, Expression::Constant(0));
+        let equal = Expression::operation(Operator::Equal, digit.clone(), digit.clone());
+        assert_eq!(*equal, Expression::Constant(1));
+        let remainder = Expression::operation(Operator::Mod, digit.clone(), constant(26));
+        assert_eq!(remainder, digit);
+        let sum = Expression::operation(Operator::Add, digit.clone(), constant(3));
+        let sum = Expression::operation(Operator::Add, sum, constant(4));
+        assert_eq!(sum.to_string(), "d1 + 7");
+        assert_eq!(sum.range(), (8, 16));
+        // Undefined operations are left alone rather than folded.
+        let quotient = Expression::operation(Operator::Divide, constant(5), constant(0));
+        assert_eq!(quotient.to_string(), "5 / 0");
+    }
+
+    #[test]
+    fn compiled_programs_agree_with_the_alu() {
+        let monad = program(&[
+            (1, 12, 12), (1, 11, 8), (1, 13, 7), (1, 14, 4), (26, -11, 5), (1, 10, 3), (26, -3, 9),
+            (1, 12, 12), (26, -7, 2), (26, -4, 6), (26, -13, 1), (26, -8, 11), (1, 15, 6), (26, -7, 14)
+        ]);
+        let compiled = CompiledProgram::compile(&monad).unwrap();
+        // Every block's `mul x 0`, `mul y 0`, and `add y 25` fold away.
+        assert!(compiled.len() <= 14 * 15, "{}", compiled.len());
+
+        let mut rng = Lcg::new(24);
+        for _ in 0..1000 {
+            let digits: Vec<isize> = (0..14).map(|_| rng.below(9) as isize + 1).collect();
+            let mut alu = ArithmeticLogicUnit::new(&monad, digits.clone()).unwrap();
+            alu.run().unwrap();
+            assert_eq!(compiled.run(&digits).unwrap(), alu.registers(), "{:?}", digits);
+        }
+    }
+
+    #[test]
+    fn compiles_away_what_doesnt_depend_on_inputs() {
+        let compiled = CompiledProgram::compile("add x 3\nmul x 5\nadd y x\neql y 15\ninp z\nmul w 0").unwrap();
+        assert_eq!(compiled.ops, vec![Op::Input(3), Op::Set(1, 15), Op::Set(2, 1)]);
+
+        // The first `add x w` is overwritten before anything reads it.
+        let compiled = CompiledProgram::compile("inp w\nadd x w\nmul x 0\nadd x w\nadd x w").unwrap();
+        assert_eq!(compiled.ops, vec![Op::Input(0), Op::Copy(1, 0), Op::AddRegister(1, 0)]);
+        assert_eq!(compiled.run(&[4]).unwrap(), [4, 8, 0, 0]);
+    }
+
+    #[test]
+    fn compiled_programs_still_fail_at_run_time() {
+        let program = "add y 2\ninp w\ndiv x w\nmod z 0";
+        let compiled = CompiledProgram::compile(program).unwrap();
+        assert_eq!(compiled.run(&[0]), Err(AluError::DivisionByZero {
+            index: 2,
+            instruction: Instruction::Divide(Register::X, Value::Register(Register::W)),
+            registers: [0, 0, 2, 0]
+        }));
+        for inputs in [vec![1], vec![]] {
+            let mut alu = ArithmeticLogicUnit::new(program, inputs.clone()).unwrap();
+            assert_eq!(compiled.run(&inputs), Err(alu.run().unwrap_err()), "{:?}", inputs);
+        }
+    }
+
+    #[test]
+    fn compiled_programs_overflow_where_the_alu_does() {
+        let programs = [
+            ("inp w\nmul w w\nmul w w\nmul w w", vec![1 << 20]),
+            // Nothing reads the square before it's zeroed, but it still overflows.
+            ("inp w\nmul w w\nmul w 0", vec![1 << 40]),
+            ("add x 9223372036854775807\nadd x 1", vec![]),
+            ("inp x\nadd x x", vec![isize::MAX]),
+            ("add x -9223372036854775807\nadd x -1\ndiv x -1", vec![]),
+            ("inp x\ninp y\ndiv x y", vec![isize::MIN, -1])
+        ];
+        for (program, inputs) in programs {
+            let mut alu = ArithmeticLogicUnit::new(program, inputs.clone()).unwrap();
+            let error = alu.run().unwrap_err();
+            assert!(matches!(error, AluError::Overflow { .. }), "{}", program);
+            assert_eq!(CompiledProgram::compile(program).unwrap().run(&inputs), Err(error), "{}", program);
+        }
+    }
+
+    #[test]
+    fn stops_where_the_puzzle_says_to() {
+        let run = |program: &str, inputs: Vec<isize>| ArithmeticLogicUnit::new(program, inputs).unwrap().run();
+
+        assert_eq!(run("inp w\nadd x 4\ndiv x w", vec![0]), Err(AluError::DivisionByZero {
+            index: 2,
+            instruction: Instruction::Divide(Register::X, Value::Register(Register::W)),
+            registers: [0, 4, 0, 0]
+        }));
+        assert!(matches!(run("add x 3\nmod x 0", vec![]), Err(AluError::DivisionByZero { index: 1, .. })));
+        assert!(matches!(run("add x -3\nmod x 2", vec![]), Err(AluError::NegativeModulo { index: 1, .. })));
+        assert!(matches!(run("add x 3\nmod x -2", vec![]), Err(AluError::NegativeModulo { index: 1, .. })));
+        assert!(matches!(
+            run("inp w\nmul w w\nmul w w\nmul w w\nmul w w\nmul w w", vec![1 << 20]),
+            Err(AluError::Overflow { index: 2, .. })
+        ));
+        // Division rounds towards zero, whether it's worked out at compile
+        // time or not.
+        let mut alu = ArithmeticLogicUnit::new("add x -7\ndiv x 2\ninp y\ndiv y 2", vec![-7]).unwrap();
+        alu.run().unwrap();
+        assert_eq!(alu.registers(), [0, -3, -3, 0]);
+        let compiled = CompiledProgram::compile("add x -7\ndiv x 2\ninp y\ndiv y 2").unwrap();
+        assert_eq!(compiled.run(&[-7]), Ok([0, -3, -3, 0]));
+
+        let error = run("inp w\ninp x", vec![5]).unwrap_err();
+        assert_eq!(error.to_string(), "Not enough inputs at instruction 1 \"inp x\" with w=5 x=0 y=0 z=0");
+    }
+
+    #[test]
+    fn traces_every_instruction() {
+        let mut alu = ArithmeticLogicUnit::new("inp w\nadd x w\nmul x 3\nmod x 4", vec![5]).unwrap();
+        alu.run().unwrap();
+        assert_eq!(alu.trace(), None);
+
+        alu.enable_trace();
+        alu.reset(&[5]);
+        alu.run().unwrap();
+        assert_eq!(alu.trace().unwrap(), &[[5, 0, 0, 0], [5, 5, 0, 0], [5, 15, 0, 0], [5, 3, 0, 0]]);
+
+        // Runs that fail keep what happened up to the failure.
+        alu.reset(&[-5]);
+        assert!(alu.run().is_err());
+        assert_eq!(alu.trace().unwrap(), &[[-5, 0, 0, 0], [-5, -5, 0, 0], [-5, -15, 0, 0]]);
+    }
+}
@@ -0,0 +1,472 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::str::FromStr;
+use num_bigint::BigUint;
+use crate::aoc_error::AocError;
+use crate::years::View;
+
+pub const NAME: &str = "Dirac Dice";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Game {
+    // 0-indexed position on the board
+    pub position: usize,
+    pub score: usize
+}
+
+// Everything about a game of Dirac Dice that the puzzle could have picked
+// differently.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Rules {
+    pub players: usize,
+    pub board_size: usize,
+    pub die_sides: usize,
+    pub rolls_per_turn: usize,
+    pub winning_score: usize
+}
+
+pub const PRACTICE: Rules = Rules { players: 2, board_size: 10, die_sides: 100, rolls_per_turn: 3, winning_score: 1000 };
+pub const DIRAC: Rules = Rules { players: 2, board_size: 10, die_sides: 3, rolls_per_turn: 3, winning_score: 21 };
+
+// How the universes branching off from some point in a game turn out. Counts
+// get big quickly, so they're BigUints.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcomes {
+    // Universes each player wins in, in the same order as the players
+    pub wins: Vec<BigUint>,
+    // Universes by how many more turns the game lasts
+    pub lengths: Vec<BigUint>,
+    // The winner's lead over the next best score, added up over every universe
+    pub total_margin: BigUint
+}
+
+// Every universe a game could be in between turns, with the players listed
+// from whoever's turn it is, mapped to how things turn out from there.
+#[derive(Debug)]
+struct MultiverseMemo {
+    states: HashMap<Vec<Game>, Rc<Outcomes>>
+}
+
+// A player's starting line, "Player N starting position: X", where X is the
+// 1-indexed space on the board.
+impl FromStr for Game {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let position: usize = s.split_once(" starting position: ")
+            .filter(|(player, _)| player.starts_with("Player "))
+            .ok_or_else(|| AocError::Misc(format!("Bad starting position \"{}\"", s)))?
+            .1
+            .parse()?;
+        if position == 0 {
+            return Err(AocError::Misc("Starting positions start at 1".to_string()));
+        }
+
+        Ok(Game::new(position - 1))
+    }
+}
+
+impl Game {
+    fn new(position: usize) -> Self {
+        Game { position, score: 0 }
+    }
+
+    fn turn(&self, roll: usize, board_size: usize) -> Self {
+        let new_pos = (self.position + roll) % board_size;
+        Game {
+            position: new_pos,
+            // Add one here to shift 0-indexed positions into 1-indexed scores
+            score: self.score + new_pos + 1
+        }
+    }
+}
+
+impl Rules {
+    fn has_won(&self, game: &Game) -> bool {
+        game.score >= self.winning_score
+    }
+
+    // Games always end, since every turn scores at least a point, but they
+    // need a board, a die, a roll, and a score to play to to get going.
+    fn check(&self, players: &[Game]) -> Result<(), AocError> {
+        if self.board_size == 0 || self.die_sides == 0 || self.rolls_per_turn == 0 || self.winning_score == 0 {
+            return Err(AocError::Misc(format!("Can't play with {:?}", self)));
+        }
+        if self.players == 0 {
+            return Err(AocError::Misc("Can't play without any players".to_string()));
+        }
+        if players.len() != self.players {
+            return Err(AocError::Misc(format!(
+                "Expected {} players, but the input has {}", self.players, players.len()
+            )));
+        }
+        if let Some(game) = players.iter().find(|game| game.position >= self.board_size) {
+            return Err(AocError::Misc(format!(
+                "Starting position {} isn't on a board of {}", game.position + 1, self.board_size
+            )));
+        }
+
+        Ok(())
+    }
+
+    // (roll_value, number_of_combinations) for the total of a turn's rolls,
+    // built up a die at a time.
+    fn rolls(&self) -> Vec<(usize, usize)> {
+        let mut combinations = vec![1];
+        for _ in 0..self.rolls_per_turn {
+            let mut next = vec![0; combinations.len() + self.die_sides];
+            for (total, count) in combinations.iter().enumerate() {
+                for side in 1..=self.die_sides {
+                    next[total + side] += count;
+                }
+            }
+            combinations = next;
+        }
+
+        combinations.into_iter()
+            .enumerate()
+            .filter(|(_, count)| *count > 0)
+            .collect()
+    }
+}
+
+impl MultiverseMemo {
+    fn new() -> Self {
+        MultiverseMemo { states: HashMap::new() }
+    }
+
+    fn get(&self, players: &[Game]) -> Option<Rc<Outcomes>> {
+        self.states.get(players).cloned()
+    }
+
+    fn insert(&mut self, players: Vec<Game>, outcomes: Rc<Outcomes>) {
+        self.states.insert(players, outcomes);
+    }
+}
+
+// How a game with a deterministic die finished.
+#[derive(Debug)]
+pub struct Finish {
+    pub winner: usize,
+    pub players: Vec<Game>,
+    pub rolls: usize
+}
+
+// Plays with a die that rolls 1 up to its number of sides and then starts
+// over at 1.
+pub fn play_deterministic(rules: &Rules, players: &[Game]) -> Result<Finish, AocError> {
+    rules.check(players)?;
+    let mut players = players.to_vec();
+    let mut die = (1..=rules.die_sides).cycle();
+    let mut rolls = 0;
+
+    for current in (0..players.len()).cycle() {
+        let roll: usize = die.by_ref().take(rules.rolls_per_turn).sum();
+        rolls += rules.rolls_per_turn;
+        players[current] = players[current].turn(roll, rules.board_size);
+        if rules.has_won(&players[current]) {
+            return Ok(Finish { winner: current, players, rolls });
+        }
+    }
+
+    unreachable!("Players take turns forever")
+}
+
+impl Outcomes {
+    // A game that's just been won.
+    fn finished(players: &[Game], winner: usize) -> Self {
+        let runner_up = players.iter()
+            .enumerate()
+            .filter(|(i, _)| *i != winner)
+            .map(|(_, game)| game.score)
+            .max()
+            .unwrap_or(0);
+
+        let mut wins = vec![BigUint::default(); players.len()];
+        wins[winner] = BigUint::from(1u32);
+        Outcomes {
+            wins,
+            lengths: vec![BigUint::from(1u32)],
+            total_margin: BigUint::from(players[winner].score - runner_up)
+        }
+    }
+
+    pub fn universes(&self) -> BigUint {
+        self.wins.iter().sum()
+    }
+}
+
+// Players take turns from the front of the list, so after each turn whoever
+// just went moves to the back.
+fn play_multiversal(
+    memo: &mut MultiverseMemo,
+    rules: &Rules,
+    rolls: &[(usize, usize)],
+    players: &[Game]
+) -> Rc<Outcomes> {
+    if let Some(winner) = players.iter().position(|game| rules.has_won(game)) {
+        return Rc::new(Outcomes::finished(players, winner));
+    }
+
+    if let Some(outcomes) = memo.get(players) {
+        return outcomes;
+    }
+
+    let mut outcomes = Outcomes {
+        wins: vec![BigUint::default(); players.len()],
+        lengths: Vec::new(),
+        total_margin: BigUint::default()
+    };
+    let mut next: Vec<Game> = players[1..].to_vec();
+    next.push(players[0]);
+    let last = next.len() - 1;
+    for (roll, multiplier) in rolls {
+        next[last] = players[0].turn(*roll, rules.board_size);
+        let after = play_multiversal(memo, rules, rolls, &next);
+
+        for (i, count) in after.wins.iter().enumerate() {
+            outcomes.wins[(i + 1) % players.len()] += count * multiplier;
+        }
+        if outcomes.lengths.len() < after.lengths.len() + 1 {
+            outcomes.lengths.resize(after.lengths.len() + 1, BigUint::default());
+        }
+        for (turns, count) in after.lengths.iter().enumerate() {
+            outcomes.lengths[turns + 1] += count * multiplier;
+        }
+        outcomes.total_margin += &after.total_margin * multiplier;
+    }
+
+    let outcomes = Rc::new(outcomes);
+    memo.insert(players.to_vec(), outcomes.clone());
+    outcomes
+}
+
+// How every universe turns out, starting with player 1's turn.
+pub fn play_quantum(rules: &Rules, players: &[Game]) -> Result<Rc<Outcomes>, AocError> {
+    rules.check(players)?;
+    Ok(play_multiversal(&mut MultiverseMemo::new(), rules, &rules.rolls(), players))
+}
+
+// numerator / denominator as a decimal, rounded to `places` places.
+fn ratio(numerator: &BigUint, denominator: &BigUint, places: u32) -> String {
+    let scale = BigUint::from(10u32).pow(places);
+    let scaled = (numerator * &scale * 2u32 + denominator) / (denominator * 2u32);
+    if places == 0 {
+        return scaled.to_string();
+    }
+
+    let digits = format!("{:0>width$}", scaled.to_string(), width = places as usize + 1);
+    let (whole, fraction) = digits.split_at(digits.len() - places as usize);
+    format!("{}.{}", whole, fraction)
+}
+
+// Players are numbered from 1 in the order they take turns.
+fn parse_players(input: &str) -> Result<Vec<Game>, AocError> {
+    input.lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+        .map(|(i, line)| {
+            if !line.starts_with(&format!("Player {} ", i + 1)) {
+                return Err(AocError::Misc(format!("Expected player {} in \"{}\"", i + 1, line)));
+            }
+            line.trim().parse()
+        })
+        .collect()
+}
+
+// The players in the input, checked against the rules they'll play by.
+pub fn parse_game(input: &str, rules: &Rules) -> Result<Vec<Game>, AocError> {
+    let players = parse_players(input)?;
+    rules.check(&players)?;
+
+    Ok(players)
+}
+
+pub fn parse(input: &str) -> Result<(), AocError> {
+    parse_game(input, &PRACTICE)?;
+
+    Ok(())
+}
+
+pub fn part_one(input: &str) -> Result<String, AocError> {
+    let finish = play_deterministic(&PRACTICE, &parse_game(input, &PRACTICE)?)?;
+    let loser_score = finish.players.iter()
+        .enumerate()
+        .filter(|(i, _)| *i != finish.winner)
+        .map(|(_, game)| game.score)
+        .min()
+        .ok_or_else(|| AocError::Misc("Nobody lost".to_string()))?;
+
+    Ok((loser_score * finish.rolls).to_string())
+}
+
+pub fn part_two(input: &str) -> Result<String, AocError> {
+    let outcomes = play_quantum(&DIRAC, &parse_game(input, &DIRAC)?)?;
+
+    Ok(outcomes.wins.iter().max().unwrap().to_string())
+}
+
+// Each player's chances, how long games go on for, and how much they're won
+// by, with the Dirac die and an optional winning score.
+fn render_odds(input: &str, args: &[String]) -> Result<Vec<String>, AocError> {
+    let rules = match args.first() {
+        Some(score) => Rules { winning_score: score.parse()?, ..DIRAC },
+        None => DIRAC
+    };
+    let outcomes = play_quantum(&rules, &parse_game(input, &rules)?)?;
+    let universes = outcomes.universes();
+
+    let wins = outcomes.wins.iter()
+        .enumerate()
+        .map(|(i, wins)| format!(
+            "Player {} wins in {} of {} universes, {}%",
+            i + 1, wins, universes, ratio(&(wins * 100u32), &universes, 4)
+        ))
+        .collect::<Vec<String>>();
+
+    let mut lengths = vec!["Turns  Universes".to_string()];
+    for (turns, count) in outcomes.lengths.iter().enumerate().filter(|(_, count)| **count > BigUint::default()) {
+        lengths.push(format!("{:>5}  {} ({}%)", turns, count, ratio(&(count * 100u32), &universes, 4)));
+    }
+
+    let margin = format!(
+        "The winner wins by {} points on average",
+        ratio(&outcomes.total_margin, &universes, 4)
+    );
+
+    Ok(vec![wins.join("\n"), lengths.join("\n"), margin])
+}
+
+pub const VIEWS: &[View] = &[
+    View {
+        name: "odds",
+        description: "win chances, game lengths, and winning margins [WINNING_SCORE]",
+        render: render_odds,
+        args_replace_input: false
+    }
+];
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use super::{parse, parse_game, play_deterministic, play_quantum, ratio, Game, Outcomes, Rules, DIRAC};
+
+    const EXAMPLE: &str = "Player 1 starting position: 4\nPlayer 2 starting position: 8";
+
+    #[test]
+    fn rejects_bad_starting_positions() {
+        assert!(parse(EXAMPLE).is_ok());
+        assert!(parse("Player 1 starting position: 4\nPlayer 2 starting position: 8\n").is_ok());
+        assert!(parse("Player 1 starting position: 4").is_err());
+        assert!(parse("").is_err());
+        assert!(parse("Player 2 starting position: 4\nPlayer 1 starting position: 8").is_err());
+        assert!(parse("Player 1 starting position: 0\nPlayer 2 starting position: 8").is_err());
+        assert!(parse("Player 1 starting position: 11\nPlayer 2 starting position: 8").is_err());
+        assert!(parse("Player 1 starting position: four\nPlayer 2 starting position: 8").is_err());
+        assert!(parse("Player 1 at 4\nPlayer 2 starting position: 8").is_err());
+    }
+
+    #[test]
+    fn works_out_roll_distributions() {
+        assert_eq!(DIRAC.rolls(), vec![(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)]);
+        let rules = Rules { die_sides: 6, rolls_per_turn: 2, ..DIRAC };
+        assert_eq!(rules.rolls(), vec![(2, 1), (3, 2), (4, 3), (5, 4), (6, 5), (7, 6), (8, 5), (9, 4), (10, 3), (11, 2), (12, 1)]);
+        let rules = Rules { die_sides: 1, rolls_per_turn: 4, ..DIRAC };
+        assert_eq!(rules.rolls(), vec![(4, 1)]);
+    }
+
+    // Every universe followed one at a time, with no memo, tallying up the
+    // same outcomes.
+    fn count_universes(rules: &Rules, players: &mut Vec<Game>, current: usize, turns: usize, outcomes: &mut Outcomes) {
+        if let Some(winner) = players.iter().position(|game| rules.has_won(game)) {
+            let finished = Outcomes::finished(players, winner);
+            outcomes.wins[winner] += 1u32;
+            if outcomes.lengths.len() <= turns {
+                outcomes.lengths.resize(turns + 1, BigUint::default());
+            }
+            outcomes.lengths[turns] += 1u32;
+            outcomes.total_margin += finished.total_margin;
+            return;
+        }
+
+        let rolls = (0..rules.rolls_per_turn).fold(vec![0], |totals, _| {
+            totals.iter().flat_map(|total| (1..=rules.die_sides).map(move |side| total + side)).collect()
+        });
+        for roll in rolls {
+            let before = players[current];
+            players[current] = before.turn(roll, rules.board_size);
+            count_universes(rules, players, (current + 1) % players.len(), turns + 1, outcomes);
+            players[current] = before;
+        }
+    }
+
+    #[test]
+    fn plays_quantum_games_with_any_rules() {
+        let configurations = [
+            (Rules { winning_score: 5, ..DIRAC }, "Player 1 starting position: 4\nPlayer 2 starting position: 8"),
+            (Rules { players: 2, board_size: 7, die_sides: 4, rolls_per_turn: 2, winning_score: 6 }, "Player 1 starting position: 7\nPlayer 2 starting position: 1"),
+            (Rules { players: 3, die_sides: 2, rolls_per_turn: 2, winning_score: 8, ..DIRAC }, "Player 1 starting position: 1\nPlayer 2 starting position: 2\nPlayer 3 starting position: 3"),
+            (Rules { players: 1, board_size: 5, die_sides: 2, rolls_per_turn: 1, winning_score: 12 }, "Player 1 starting position: 5")
+        ];
+        for (rules, input) in configurations {
+            let mut players = parse_game(input, &rules).unwrap();
+            let mut outcomes = Outcomes {
+                wins: vec![BigUint::default(); players.len()],
+                lengths: Vec::new(),
+                total_margin: BigUint::default()
+            };
+            count_universes(&rules, &mut players, 0, 0, &mut outcomes);
+            assert_eq!(*play_quantum(&rules, &players).unwrap(), outcomes, "{:?}", rules);
+        }
+    }
+
+    #[test]
+    fn counts_past_usize() {
+        let rules = Rules { winning_score: 40, ..DIRAC };
+        let outcomes = play_quantum(&rules, &parse_game(EXAMPLE, &rules).unwrap()).unwrap();
+        assert!(outcomes.universes() > BigUint::from(u64::MAX));
+        assert_eq!(outcomes.lengths.iter().sum::<BigUint>(), outcomes.universes());
+    }
+
+    #[test]
+    fn works_out_exact_odds() {
+        let outcomes = play_quantum(&DIRAC, &parse_game(EXAMPLE, &DIRAC).unwrap()).unwrap();
+        assert_eq!(outcomes.wins, vec![BigUint::from(444356092776315u64), BigUint::from(341960390180808u64)]);
+        assert_eq!(ratio(&(&outcomes.wins[0] * 100u32), &outcomes.universes(), 4), "56.5111");
+
+        assert_eq!(ratio(&BigUint::from(1u32), &BigUint::from(3u32), 3), "0.333");
+        assert_eq!(ratio(&BigUint::from(2u32), &BigUint::from(3u32), 3), "0.667");
+        assert_eq!(ratio(&BigUint::from(5u32), &BigUint::from(2u32), 0), "3");
+        assert_eq!(ratio(&BigUint::from(1u32), &BigUint::from(400u32), 2), "0.00");
+        assert_eq!(ratio(&BigUint::from(12u32), &BigUint::from(1u32), 1), "12.0");
+    }
+
+    #[test]
+    fn plays_deterministic_games_with_any_rules() {
+        // A one-sided die only has one universe to play in.
+        let rules = Rules { players: 3, die_sides: 1, winning_score: 50, ..DIRAC };
+        let players = parse_game("Player 1 starting position: 2\nPlayer 2 starting position: 9\nPlayer 3 starting position: 5", &rules).unwrap();
+        let finish = play_deterministic(&rules, &players).unwrap();
+        let mut wins = vec![BigUint::default(); 3];
+        wins[finish.winner] = BigUint::from(1u32);
+        assert_eq!(play_quantum(&rules, &players).unwrap().wins, wins);
+
+        // Player 1 moves 1 + 2 = 3 spaces a turn from 1, scoring 4, 7, and 10.
+        let rules = Rules { players: 1, board_size: 10, die_sides: 2, rolls_per_turn: 2, winning_score: 20 };
+        let finish = play_deterministic(&rules, &parse_game("Player 1 starting position: 1", &rules).unwrap()).unwrap();
+        assert_eq!((finish.winner, finish.players[0].score, finish.rolls), (0, 21, 6));
+
+        assert!(parse_game("Player 1 starting position: 8", &Rules { players: 1, board_size: 7, ..DIRAC }).is_err());
+        assert!(parse_game("Player 1 starting position: 1", &Rules { players: 1, die_sides: 0, ..DIRAC }).is_err());
+        assert!(parse_game("Player 1 starting position: 1", &Rules { players: 0, ..DIRAC }).is_err());
+        // Nobody can have won before the first roll.
+        let rules = Rules { winning_score: 0, ..DIRAC };
+        assert!(parse_game(EXAMPLE, &rules).is_err());
+        let players = parse_game(EXAMPLE, &DIRAC).unwrap();
+        assert!(play_deterministic(&rules, &players).is_err());
+        assert!(play_quantum(&rules, &players).is_err());
+        // The rules say how many play, and the input has to agree.
+        assert!(parse_game("Player 1 starting position: 1", &DIRAC).is_err());
+        assert!(parse_game(&format!("{}\nPlayer 3 starting position: 5", EXAMPLE), &DIRAC).is_err());
+    }
+}
@@ -0,0 +1,590 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use crate::aoc_error::AocError;
+use crate::years::View;
+
+pub const NAME: &str = "Amphipod";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Species { Amber, Bronze, Copper, Desert }
+
+impl Species {
+    fn cost(&self) -> usize {
+        match self {
+            Species::Amber => 1,
+            Species::Bronze => 10,
+            Species::Copper => 100,
+            Species::Desert => 1000
+        }
+    }
+
+    fn char(&self) -> char {
+        match self {
+            Species::Amber => 'A',
+            Species::Bronze => 'B',
+            Species::Copper => 'C',
+            Species::Desert => 'D'
+        }
+    }
+
+    fn index(&self) -> usize {
+        match self {
+            Species::Amber => 0,
+            Species::Bronze => 1,
+            Species::Copper => 2,
+            Species::Desert => 3
+        }
+    }
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'A' => Some(Species::Amber),
+            'B' => Some(Species::Bronze),
+            'C' => Some(Species::Copper),
+            'D' => Some(Species::Desert),
+            _ => None
+        }
+    }
+}
+
+const SPECIES: [Species; 4] = [Species::Amber, Species::Bronze, Species::Copper, Species::Desert];
+
+// The x-positions of the room entrances, in order of the species they're for.
+const ROOMS: [usize; 4] = [2, 4, 6, 8];
+
+// Limits of what fits in a `State`.
+const MAX_HALLWAY_LENGTH: usize = 21;
+const MAX_ROOM_DEPTH: usize = 6;
+
+// Where every amphipod is, packed into two integers so states are cheap to
+// copy, compare and hash. Each hallway space takes 3 bits, 0 when it's empty
+// and otherwise one more than the species' index. Each room takes 16 bits: how
+// many amphipods are in it in the low 4 bits, then 2 bits per amphipod from
+// the bottom up.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct State {
+    hallway: u64,
+    rooms: u64
+}
+
+impl State {
+    fn hallway_at(&self, position: usize) -> Option<Species> {
+        match (self.hallway >> (position * 3)) & 0b111 {
+            0 => None,
+            n => Some(SPECIES[n as usize - 1])
+        }
+    }
+
+    fn with_hallway(self, position: usize, species: Option<Species>) -> State {
+        let shift = position * 3;
+        let value = species.map_or(0, |species| species.index() as u64 + 1);
+        State { hallway: (self.hallway & !(0b111 << shift)) | (value << shift), ..self }
+    }
+
+    fn room(&self, room: usize) -> u64 {
+        (self.rooms >> (room * 16)) & 0xFFFF
+    }
+
+    fn with_room(self, room: usize, bits: u64) -> State {
+        let shift = room * 16;
+        State { rooms: (self.rooms & !(0xFFFF << shift)) | (bits << shift), ..self }
+    }
+
+    fn room_len(&self, room: usize) -> usize {
+        (self.room(room) & 0xF) as usize
+    }
+
+    // The amphipod `index` places up from the bottom of a room.
+    fn room_pod(&self, room: usize, index: usize) -> Species {
+        SPECIES[((self.room(room) >> (4 + 2 * index)) & 0b11) as usize]
+    }
+
+    fn push(self, room: usize, species: Species) -> State {
+        let bits = self.room(room);
+        let len = bits & 0xF;
+        let pods = (bits >> 4) | ((species.index() as u64) << (2 * len));
+        self.with_room(room, (pods << 4) | (len + 1))
+    }
+
+    fn pop(self, room: usize) -> (State, Species) {
+        let len = self.room_len(room);
+        let species = self.room_pod(room, len - 1);
+        let pods = (self.room(room) >> 4) & !(0b11 << (2 * (len - 1)));
+        (self.with_room(room, (pods << 4) | (len as u64 - 1)), species)
+    }
+
+    // Whether a room only has amphipods that belong there.
+    fn room_is_ready(&self, room: usize) -> bool {
+        (0..self.room_len(room)).all(|index| self.room_pod(room, index).index() == room)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Burrow {
+    hallway_length: usize,
+    room_depth: usize,
+    start: State
+}
+
+impl FromStr for Burrow {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = s.lines().collect();
+        let hallway = lines.get(1)
+            .and_then(|line| line.trim().strip_prefix('#'))
+            .and_then(|line| line.strip_suffix('#'))
+            .ok_or_else(|| AocError::Misc("Missing hallway".to_string()))?;
+        let hallway_length = hallway.chars().count();
+        if hallway_length <= ROOMS[ROOMS.len() - 1] {
+            return Err(AocError::Misc("Hallway too short for the rooms".to_string()));
+        }
+        if hallway_length > MAX_HALLWAY_LENGTH {
+            return Err(AocError::Misc(format!("Hallways longer than {} aren't supported", MAX_HALLWAY_LENGTH)));
+        }
+
+        let mut start = State { hallway: 0, rooms: 0 };
+        let mut counts = [0; 4];
+        for (position, c) in hallway.chars().enumerate() {
+            if c != '.' {
+                let species = Species::from_char(c)
+                    .ok_or_else(|| AocError::Misc(format!("Bad hallway space '{}'", c)))?;
+                if ROOMS.contains(&position) {
+                    return Err(AocError::Misc("Amphipod stopped outside a room".to_string()));
+                }
+                start = start.with_hallway(position, Some(species));
+                counts[species.index()] += 1;
+            }
+        }
+
+        // Rooms run down from the hallway until the line closing them off,
+        // with their spaces lined up under the hallway's.
+        let room_lines: Vec<Vec<char>> = lines.iter()
+            .skip(2)
+            .take_while(|line| line.chars().any(|c| c != '#' && c != ' '))
+            .map(|line| line.chars().collect())
+            .collect();
+        let room_depth = room_lines.len();
+        if room_depth == 0 {
+            return Err(AocError::Misc("Missing rooms".to_string()));
+        }
+        if room_depth > MAX_ROOM_DEPTH {
+            return Err(AocError::Misc(format!("Rooms deeper than {} aren't supported", MAX_ROOM_DEPTH)));
+        }
+
+        for line in room_lines.iter() {
+            for (column, c) in line.iter().enumerate() {
+                if !matches!(c, '#' | ' ') && !ROOMS.contains(&column.wrapping_sub(1)) {
+                    return Err(AocError::Misc(format!("Unexpected '{}' outside a room", c)));
+                }
+            }
+        }
+
+        // Rooms are stacks, so they're filled from the bottom line up.
+        for (room, x) in ROOMS.iter().enumerate() {
+            for (depth, line) in room_lines.iter().enumerate().rev() {
+                match line.get(x + 1) {
+                    Some('.') => {},
+                    Some(c) => {
+                        let species = Species::from_char(*c)
+                            .ok_or_else(|| AocError::Misc(format!("Bad room space '{}'", c)))?;
+                        if start.room_len(room) != room_depth - 1 - depth {
+                            return Err(AocError::Misc(format!("Amphipod floating above empty space in room {}", x)));
+                        }
+                        start = start.push(room, species);
+                        counts[species.index()] += 1;
+                    },
+                    None => return Err(AocError::Misc(format!("Room {} is missing a space", x)))
+                }
+            }
+        }
+
+        for species in SPECIES {
+            if counts[species.index()] != room_depth {
+                return Err(AocError::Misc(format!(
+                    "{} {} amphipods for rooms {} deep", counts[species.index()], species.char(), room_depth
+                )));
+            }
+        }
+
+        Ok(Burrow { hallway_length, room_depth, start })
+    }
+}
+
+impl Display for Burrow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "{}", self.draw(self.start))
+    }
+}
+
+impl Burrow {
+    // Draws a state of the burrow the way the puzzle does.
+    fn draw(&self, state: State) -> String {
+        let hallway: String = (0..self.hallway_length)
+            .map(|position| state.hallway_at(position).map_or('.', |species| species.char()))
+            .collect();
+
+        // The top row of rooms is walled in all the way along, the rest only
+        // around the rooms themselves.
+        let width = self.hallway_length + 2;
+        let walls = |full: bool| -> Vec<char> {
+            (0..width)
+                .map(|column| {
+                    if full || (ROOMS[0]..=ROOMS[ROOMS.len() - 1] + 2).contains(&column) { '#' } else { ' ' }
+                })
+                .collect()
+        };
+
+        let mut lines = vec!["#".repeat(width), format!("#{}#", hallway)];
+        for depth in 0..self.room_depth {
+            let mut line = walls(depth == 0);
+            for (room, x) in ROOMS.iter().enumerate() {
+                let index = self.room_depth - 1 - depth;
+                if index < state.room_len(room) {
+                    line[x + 1] = state.room_pod(room, index).char();
+                } else {
+                    line[x + 1] = '.';
+                }
+            }
+            lines.push(line.iter().collect::<String>().trim_end().to_string());
+        }
+        lines.push(walls(false).iter().collect::<String>().trim_end().to_string());
+
+        lines.join("\n")
+    }
+
+    fn is_sorted(&self, state: State) -> bool {
+        state.hallway == 0 && (0..ROOMS.len()).all(|room| {
+            state.room_len(room) == self.room_depth && state.room_is_ready(room)
+        })
+    }
+
+    // Whether the hallway is empty from `from` (not counting whoever is
+    // there) all the way to `to`.
+    fn hallway_is_clear(state: State, from: usize, to: usize) -> bool {
+        let (low, high) = if from < to { (from + 1, to) } else { (to, from - 1) };
+        (low..=high).all(|position| state.hallway_at(position).is_none())
+    }
+
+    // Every state one amphipod's move away, with the energy it takes.
+    fn moves(&self, state: State) -> Vec<(State, usize)> {
+        // Moving an amphipod straight into its own room never hurts, so when
+        // one can that's the only move worth trying.
+        for position in 0..self.hallway_length {
+            if let Some(species) = state.hallway_at(position) {
+                let room = species.index();
+                let x = ROOMS[room];
+                if state.room_is_ready(room) && Burrow::hallway_is_clear(state, position, x) {
+                    let steps = position.abs_diff(x) + self.room_depth - state.room_len(room);
+                    let next = state.with_hallway(position, None).push(room, species);
+                    return vec![ (next, steps * species.cost()) ];
+                }
+            }
+        }
+
+        let mut moves = Vec::new();
+        for (room, x) in ROOMS.iter().enumerate() {
+            let len = state.room_len(room);
+            // Nobody in a room of their own kind needs to leave it.
+            if len == 0 || state.room_is_ready(room) {
+                continue;
+            }
+
+            let (popped, species) = state.pop(room);
+            let steps_out = self.room_depth - len + 1;
+            let left = (0..*x).rev();
+            let right = x + 1..self.hallway_length;
+            for direction in [left.collect::<Vec<usize>>(), right.collect()] {
+                for position in direction {
+                    if state.hallway_at(position).is_some() { break; }
+                    // Nobody can stop in front of a room
+                    if ROOMS.contains(&position) { continue; }
+
+                    let steps = steps_out + position.abs_diff(*x);
+                    moves.push((popped.with_hallway(position, Some(species)), steps * species.cost()));
+                }
+            }
+        }
+
+        moves
+    }
+
+    // A lower bound on the energy left to spend, from moving each amphipod
+    // home as if nobody else were in the way. Everyone still to go into a room
+    // has to fill a different space in it, so those are counted too.
+    fn heuristic(&self, state: State) -> usize {
+        let mut energy = 0;
+        let mut arriving = [0usize; 4];
+
+        for position in 0..self.hallway_length {
+            if let Some(species) = state.hallway_at(position) {
+                energy += (position.abs_diff(ROOMS[species.index()]) + 1) * species.cost();
+                arriving[species.index()] += 1;
+            }
+        }
+
+        for (room, x) in ROOMS.iter().enumerate() {
+            let len = state.room_len(room);
+            // Everyone above the first amphipod from the bottom that's in the
+            // wrong room has to get out of the way.
+            let settled = (0..len).take_while(|&index| state.room_pod(room, index).index() == room).count();
+            for index in settled..len {
+                let species = state.room_pod(room, index);
+                let steps_out = self.room_depth - index;
+                let steps_across = if species.index() == room { 2 } else { x.abs_diff(ROOMS[species.index()]) };
+                energy += (steps_out + steps_across + 1) * species.cost();
+                arriving[species.index()] += 1;
+            }
+        }
+
+        // Arrivals above the first one have to stop that many spaces short.
+        for species in SPECIES {
+            let count = arriving[species.index()];
+            energy += count * count.saturating_sub(1) / 2 * species.cost();
+        }
+
+        energy
+    }
+
+    // A* over packed states. Returns the least energy it takes to sort the
+    // amphipods, and the state each state was best reached from so the moves
+    // can be traced back.
+    fn search(&self) -> Option<(usize, State, HashMap<State, State>)> {
+        let mut best: HashMap<State, usize> = HashMap::new();
+        let mut previous: HashMap<State, State> = HashMap::new();
+        let mut queue = BinaryHeap::new();
+        best.insert(self.start, 0);
+        queue.push(Reverse((self.heuristic(self.start), 0, self.start)));
+
+        while let Some(Reverse((_, energy, state))) = queue.pop() {
+            if best.get(&state).is_some_and(|best| *best < energy) {
+                continue;
+            }
+            if self.is_sorted(state) {
+                return Some((energy, state, previous));
+            }
+
+            for (next, cost) in self.moves(state) {
+                let next_energy = energy + cost;
+                if best.get(&next).is_none_or(|best| next_energy < *best) {
+                    best.insert(next, next_energy);
+                    previous.insert(next, state);
+                    queue.push(Reverse((next_energy + self.heuristic(next), next_energy, next)));
+                }
+            }
+        }
+
+        None
+    }
+
+    fn least_energy(&self) -> Option<usize> {
+        self.search().map(|(energy, _, _)| energy)
+    }
+
+    // The moves of a cheapest way to sort the amphipods, each with the state
+    // it leaves the burrow in.
+    fn solution(&self) -> Option<Vec<(Move, State)>> {
+        let (_, end, previous) = self.search()?;
+
+        let mut states = vec![end];
+        while let Some(state) = previous.get(&states[states.len() - 1]) {
+            states.push(*state);
+        }
+        states.reverse();
+
+        states.windows(2)
+            .map(|pair| self.move_between(pair[0], pair[1]).map(|step| (step, pair[1])))
+            .collect()
+    }
+
+    // Works out which amphipod moved where between two states one move apart.
+    fn move_between(&self, before: State, after: State) -> Option<Move> {
+        let position = (0..self.hallway_length).find(|&p| before.hallway_at(p) != after.hallway_at(p))?;
+        let room = (0..ROOMS.len()).find(|&room| before.room_len(room) != after.room_len(room))?;
+
+        let into_room = after.hallway_at(position).is_none();
+        let (species, depth) = if into_room {
+            (before.hallway_at(position)?, self.room_depth - after.room_len(room))
+        } else {
+            (after.hallway_at(position)?, self.room_depth - before.room_len(room))
+        };
+
+        let steps = position.abs_diff(ROOMS[room]) + depth + 1;
+        Some(Move { species, room, depth, position, into_room, energy: steps * species.cost() })
+    }
+}
+
+// One amphipod moving between a room and the hallway. Rooms are numbered from
+// 1 on the left, and depths and hallway spaces count from 0.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Move {
+    species: Species,
+    room: usize,
+    depth: usize,
+    position: usize,
+    into_room: bool,
+    energy: usize
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        let room = format!("room {} (depth {})", self.room + 1, self.depth);
+        let hallway = format!("hallway space {}", self.position);
+        let (from, to) = if self.into_room { (hallway, room) } else { (room, hallway) };
+        write!(f, "{} moves from {} to {}, using {} energy", self.species.char(), from, to, self.energy)
+    }
+}
+
+// Part two's diagram has two more rows folded out of the middle of each room.
+fn unfold(input: &str) -> String {
+    let mut lines: Vec<&str> = input.lines().collect();
+    let at = 3.min(lines.len());
+    lines.splice(at..at, ["  #D#C#B#A#", "  #D#B#A#C#"]);
+    lines.join("\n")
+}
+
+fn least_energy(burrow: &Burrow) -> Result<String, AocError> {
+    burrow.least_energy()
+        .map(|cost| cost.to_string())
+        .ok_or_else(|| AocError::Misc("The amphipods can't be sorted".to_string()))
+}
+
+// The burrow before and after every move of a cheapest solution.
+fn replay(burrow: &Burrow) -> Result<Vec<String>, AocError> {
+    let solution = burrow.solution()
+        .ok_or_else(|| AocError::Misc("The amphipods can't be sorted".to_string()))?;
+
+    let mut frames = vec![format!("Start\n{}", burrow)];
+    let mut total = 0;
+    for (i, (step, state)) in solution.iter().enumerate() {
+        total += step.energy;
+        frames.push(format!("{}. {}, {} in total\n{}", i + 1, step, total, burrow.draw(*state)));
+    }
+
+    Ok(frames)
+}
+
+fn render_replay(input: &str, args: &[String]) -> Result<Vec<String>, AocError> {
+    match args.first().map(|arg| arg.as_str()) {
+        None | Some("1") => replay(&input.parse()?),
+        Some("2") => replay(&unfold(input).parse()?),
+        Some(arg) => Err(AocError::Misc(format!("No part \"{}\"", arg)))
+    }
+}
+
+pub const VIEWS: &[View] = &[
+    View {
+        name: "replay",
+        description: "every move of a cheapest solution to part one, or two [PART]",
+        render: render_replay,
+        args_replace_input: false
+    }
+];
+
+pub fn parse(input: &str) -> Result<(), AocError> {
+    input.parse::<Burrow>()?;
+
+    Ok(())
+}
+
+pub fn part_one(input: &str) -> Result<String, AocError> {
+    least_energy(&input.parse()?)
+}
+
+pub fn part_two(input: &str) -> Result<String, AocError> {
+    least_energy(&unfold(input).parse()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{replay, unfold, Burrow, Species, State};
+
+    const EXAMPLE: &str = "#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########";
+
+    #[test]
+    fn draws_burrows_like_the_puzzle() {
+        let burrow: Burrow = EXAMPLE.parse().unwrap();
+        assert_eq!(burrow.to_string(), EXAMPLE);
+
+        let unfolded: Burrow = unfold(EXAMPLE).parse().unwrap();
+        assert_eq!(unfolded.room_depth, 4);
+        assert_eq!(unfolded.to_string(), unfold(EXAMPLE));
+    }
+
+    #[test]
+    fn packs_rooms_as_stacks() {
+        let state = State { hallway: 0, rooms: 0 }
+            .push(1, Species::Desert)
+            .push(1, Species::Amber)
+            .with_hallway(10, Some(Species::Copper));
+        assert_eq!(state.room_len(1), 2);
+        assert_eq!(state.room_pod(1, 0), Species::Desert);
+        assert_eq!(state.hallway_at(10), Some(Species::Copper));
+
+        let (state, species) = state.pop(1);
+        assert_eq!(species, Species::Amber);
+        assert_eq!(state, State { hallway: 0, rooms: 0 }.push(1, Species::Desert).with_hallway(10, Some(Species::Copper)));
+    }
+
+    #[test]
+    fn heuristic_never_overestimates() {
+        for input in [EXAMPLE.to_string(), unfold(EXAMPLE)] {
+            let burrow: Burrow = input.parse().unwrap();
+            let energy = burrow.least_energy().unwrap();
+            assert!(burrow.heuristic(burrow.start) <= energy);
+
+            // Nor for any state one move in, where some moves leave the
+            // amphipods stuck for good.
+            for (next, cost) in burrow.moves(burrow.start) {
+                if let Some(rest) = (Burrow { start: next, ..burrow.clone() }).least_energy() {
+                    assert!(burrow.heuristic(next) <= rest);
+                    assert!(cost + rest >= energy);
+                }
+            }
+        }
+
+        let sorted: Burrow = "#############\n#...........#\n###A#B#C#D###\n  #A#B#C#D#\n  #########".parse().unwrap();
+        assert_eq!(sorted.heuristic(sorted.start), 0);
+        assert_eq!(sorted.least_energy(), Some(0));
+    }
+
+    #[test]
+    fn replays_cheapest_solutions() {
+        for (input, energy) in [(EXAMPLE.to_string(), 12521), (unfold(EXAMPLE), 44169)] {
+            let burrow: Burrow = input.parse().unwrap();
+            let solution = burrow.solution().unwrap();
+            assert_eq!(solution.iter().map(|(step, _)| step.energy).sum::<usize>(), energy);
+            assert!(burrow.is_sorted(solution[solution.len() - 1].1));
+
+            // Every frame's diagram reads back in as the state it shows.
+            let frames = replay(&burrow).unwrap();
+            assert_eq!(frames.len(), solution.len() + 1);
+            for (frame, (_, state)) in frames.iter().skip(1).zip(solution.iter()) {
+                let (_, diagram) = frame.split_once('\n').unwrap();
+                assert_eq!(diagram.parse::<Burrow>().unwrap().start, *state);
+            }
+        }
+    }
+
+    #[test]
+    fn describes_moves() {
+        let burrow: Burrow = EXAMPLE.parse().unwrap();
+        let (step, _) = burrow.solution().unwrap()[0];
+        assert!(step.to_string().contains(" moves from room "), "{}", step);
+    }
+
+    #[test]
+    fn rejects_bad_diagrams() {
+        assert!("#############\n#...........#\n###B#C#B#D###\n  #########".parse::<Burrow>().is_err());
+        assert!("#############\n#...........#\n###B#C#B#D###\n  #A#D#C#E#\n  #########".parse::<Burrow>().is_err());
+        assert!("#############\n#...#".parse::<Burrow>().is_err());
+        assert!("#############\n#...........#\n###B#.#B#D###\n  #A#D#C#A#\n  #########".parse::<Burrow>().is_err());
+        assert!("#############\n#...........#\n###.#C#B#D###\n  #A#D#C#A#\n  #########".parse::<Burrow>().is_err());
+    }
+}
@@ -15,12 +15,12 @@ impl FromStr for Command {
     type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.starts_with("forward") {
-            Ok(Forward(s[8..].parse()?))
-        } else if s.starts_with("up") {
-            Ok(Up(s[3..].parse()?))
-        } else if s.starts_with("down") {
-            Ok(Down(s[5..].parse()?))
+        if let Some(distance) = s.strip_prefix("forward ") {
+            Ok(Forward(distance.parse()?))
+        } else if let Some(distance) = s.strip_prefix("up ") {
+            Ok(Up(distance.parse()?))
+        } else if let Some(distance) = s.strip_prefix("down ") {
+            Ok(Down(distance.parse()?))
         } else {
             Err(AocError::Misc(format!("Invalid command \"{}\"", s)))
         }
@@ -80,27 +80,32 @@ impl Position {
     }
 }
 
-pub fn part_one(input: &str) -> Result<String, AocError> {
-    let answer = input
+fn parse_commands(input: &str) -> Result<Vec<Command>, AocError> {
+    input
         .lines()
         .map(|l| l.parse())
-        .fold(
-            Ok(Position { horizontal: 0, depth: 0, aim: 0 }),
-            |p: Result<Position, AocError>, c| Ok(p?.update_p1(c?))
-        )?
+        .collect()
+}
+
+pub fn parse(input: &str) -> Result<(), AocError> {
+    parse_commands(input)?;
+
+    Ok(())
+}
+
+pub fn part_one(input: &str) -> Result<String, AocError> {
+    let answer = parse_commands(input)?
+        .into_iter()
+        .fold(Position { horizontal: 0, depth: 0, aim: 0 }, |p, c| p.update_p1(c))
         .product();
 
     Ok(answer.to_string())
 }
 
 pub fn part_two(input: &str) -> Result<String, AocError> {
-    let answer = input
-        .lines()
-        .map(|l| l.parse())
-        .fold(
-            Ok(Position { horizontal: 0, depth: 0, aim: 0 }),
-            |p: Result<Position, AocError>, c| Ok(p?.update_p2(c?))
-        )?
+    let answer = parse_commands(input)?
+        .into_iter()
+        .fold(Position { horizontal: 0, depth: 0, aim: 0 }, |p, c| p.update_p2(c))
         .product();
 
     Ok(answer.to_string())
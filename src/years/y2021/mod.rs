@@ -0,0 +1,35 @@
+pub mod days;
+
+use crate::years::Day;
+
+pub fn get_day(day: u32) -> Option<Day> {
+    match_day! {
+        2021 day
+
+        1 => one + parse,
+        2 => two + parse,
+        3 => three + parse,
+        4 => four + parse,
+        5 => five + parse,
+        6 => six + parse,
+        7 => seven + parse,
+        8 => eight + parse,
+        9 => nine + parse,
+        10 => ten,
+        11 => eleven + parse,
+        12 => twelve + parse,
+        13 => thirteen + parse,
+        14 => fourteen + parse,
+        15 => fifteen,
+        16 => sixteen + parse + views,
+        17 => seventeen + parse + views,
+        18 => eighteen + parse + views,
+        19 => nineteen,
+        20 => twenty,
+        21 => twentyone + parse + views,
+        22 => twentytwo,
+        23 => twentythree + parse + views,
+        24 => twentyfour + parse + views,
+        25 => twentyfive
+    }
+}
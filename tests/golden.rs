@@ -0,0 +1,159 @@
+// Golden answers for every day, driven by the files in tests/golden. Each
+// case is a `<year>/<day>/<name>.answers` file listing the expected answer for
+// either or both parts:
+//
+//     part_one: 5934
+//     part_two:
+//     multi-line answers
+//     go on the following lines
+//
+// and is run against `<name>.input` next to it. Cases without an input file
+// are checked against the cached puzzle input in inputs/<year>/<day>.txt, and
+// are skipped if it hasn't been downloaded, so these never touch the network.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use aoc21::input::get_cached_input;
+use aoc21::years::{get_day, Solution};
+
+struct Case {
+    year: u32,
+    day: u32,
+    name: String,
+    input: Option<String>,
+    part_one: Option<String>,
+    part_two: Option<String>
+}
+
+fn parse_answers(text: &str) -> (Option<String>, Option<String>) {
+    let mut part_one: Option<String> = None;
+    let mut part_two: Option<String> = None;
+    let mut current: Option<&mut Option<String>> = None;
+
+    for line in text.lines() {
+        if let Some(rest) = line.strip_prefix("part_one:") {
+            part_one = Some(rest.trim().to_string());
+            current = Some(&mut part_one);
+        } else if let Some(rest) = line.strip_prefix("part_two:") {
+            part_two = Some(rest.trim().to_string());
+            current = Some(&mut part_two);
+        } else if let Some(Some(answer)) = current.as_mut().map(|a| a.as_mut()) {
+            if !answer.is_empty() { answer.push('\n'); }
+            answer.push_str(line);
+        }
+    }
+
+    (part_one, part_two)
+}
+
+fn number_dirs(dir: &Path) -> Vec<(u32, PathBuf)> {
+    let mut dirs: Vec<(u32, PathBuf)> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .filter_map(|path| {
+            let n = path.file_name()?.to_str()?.parse().ok()?;
+            Some((n, path))
+        })
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+fn load_cases() -> Vec<Case> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden");
+    let mut cases = Vec::new();
+
+    for (year, year_dir) in number_dirs(&root) {
+        for (day, day_dir) in number_dirs(&year_dir) {
+            let mut answer_files: Vec<PathBuf> = fs::read_dir(&day_dir)
+                .unwrap()
+                .map(|entry| entry.unwrap().path())
+                .filter(|path| path.extension().is_some_and(|ext| ext == "answers"))
+                .collect();
+            answer_files.sort();
+
+            for path in answer_files {
+                let name = path.file_stem().unwrap().to_str().unwrap().to_string();
+                let (part_one, part_two) = parse_answers(&fs::read_to_string(&path).unwrap());
+                let input = fs::read_to_string(path.with_extension("input"))
+                    .ok()
+                    .map(|input| input.trim().to_string());
+
+                cases.push(Case { year, day, name, input, part_one, part_two });
+            }
+        }
+    }
+
+    cases
+}
+
+// Multi-line answers are compared ignoring trailing whitespace on each line
+// and blank lines at either end.
+fn normalize(answer: &str) -> String {
+    answer.lines()
+        .map(|line| line.trim_end())
+        .collect::<Vec<&str>>()
+        .join("\n")
+        .trim_matches('\n')
+        .to_string()
+}
+
+fn check(case: &Case, part: &str, solution: Solution, input: &str, expected: &str) -> Option<String> {
+    let label = format!("{} day {} {} {}", case.year, case.day, case.name, part);
+    match solution(input) {
+        Ok(answer) if normalize(&answer) == normalize(expected) => None,
+        Ok(answer) => Some(format!("{}: expected {:?}, got {:?}", label, expected, answer)),
+        Err(error) => Some(format!("{}: expected {:?}, got error {}", label, expected, error))
+    }
+}
+
+// Some solutions recurse deeply, so give them the same stack the runner's main
+// thread gets rather than the smaller default for test threads.
+const STACK_SIZE: usize = 8 * 1024 * 1024;
+
+#[test]
+fn golden_answers() {
+    std::thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(run_golden_cases)
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
+fn run_golden_cases() {
+    let cases = load_cases();
+    assert!(!cases.is_empty(), "No golden test cases found");
+
+    let mut failures = Vec::new();
+    for case in &cases {
+        let day = match get_day(case.year, case.day) {
+            Some(day) => day,
+            None => {
+                failures.push(format!("{} day {} {}: no such day", case.year, case.day, case.name));
+                continue;
+            }
+        };
+
+        let input = match &case.input {
+            Some(input) => input.clone(),
+            None => match get_cached_input(case.year, case.day) {
+                Ok(Some(input)) => input,
+                _ => {
+                    println!("Skipping {} day {} {}: no cached input", case.year, case.day, case.name);
+                    continue;
+                }
+            }
+        };
+
+        if let Some(expected) = &case.part_one {
+            failures.extend(check(case, "part one", day.part_one, &input, expected));
+        }
+        if let Some(expected) = &case.part_two {
+            failures.extend(check(case, "part two", day.part_two, &input, expected));
+        }
+    }
+
+    assert!(failures.is_empty(), "{} golden answers failed:\n{}", failures.len(), failures.join("\n"));
+}
@@ -0,0 +1,102 @@
+// Every day's input parser should reject malformed input with an AocError
+// rather than panicking, whatever it's given.
+
+use proptest::prelude::*;
+use aoc21::years::{get_day, get_year, Parser, YEARS};
+
+fn parsers() -> Vec<(String, Parser)> {
+    YEARS.iter()
+        .flat_map(|&year| get_year(year))
+        .filter_map(|day| day.parse.map(|parse| (format!("{} day {}", day.year, day.day), parse)))
+        .collect()
+}
+
+fn parser(year: u32, day: u32) -> Parser {
+    get_day(year, day).and_then(|day| day.parse).unwrap()
+}
+
+// Lines built from the tokens the puzzle inputs actually use, which gets much
+// deeper into the parsers than arbitrary unicode does.
+fn puzzle_like_text() -> impl Strategy<Value = String> {
+    prop::collection::vec(
+        prop_oneof![
+            "-?[0-9]{1,3}",
+            "[a-zA-Z]{1,7}",
+            "[ ,|\\[\\]=.>-]{1,2}",
+            Just("\n".to_string()),
+            Just("\n\n".to_string()),
+            Just(" -> ".to_string()),
+            Just("fold along ".to_string())
+        ],
+        0..40
+    ).prop_map(|tokens| tokens.concat())
+}
+
+fn alu_program() -> impl Strategy<Value = String> {
+    prop::collection::vec(
+        "(inp|add|mul|div|mod|eql|nop)( ([wxyz]|-?[0-9]{1,3}|[a-z]{2})){0,3}",
+        0..20
+    ).prop_map(|lines| lines.join("\n"))
+}
+
+proptest! {
+    #[test]
+    fn parsers_dont_panic_on_arbitrary_text(input in "\\PC*") {
+        for (_, parse) in parsers() {
+            let _ = parse(&input);
+        }
+    }
+
+    #[test]
+    fn parsers_dont_panic_on_puzzle_like_text(input in puzzle_like_text()) {
+        for (_, parse) in parsers() {
+            let _ = parse(&input);
+        }
+    }
+
+    #[test]
+    fn diagnostic_report_doesnt_panic(input in "[01]{0,5}(\n[01 ]{0,5}){0,8}") {
+        let day = get_day(2021, 3).unwrap();
+        let _ = parser(2021, 3)(&input);
+        let _ = (day.part_one)(&input);
+        let _ = (day.part_two)(&input);
+    }
+
+    #[test]
+    fn bits_parser_doesnt_panic_on_hex(input in "[0-9A-F]{0,40}") {
+        let _ = parser(2021, 16)(&input);
+    }
+
+    #[test]
+    fn bits_parser_rejects_non_hex(input in "[0-9A-F]{0,10}[G-Zg-z!]{1,3}[0-9A-F]{0,10}") {
+        prop_assert!(parser(2021, 16)(&input).is_err());
+    }
+
+    #[test]
+    fn snailfish_parser_doesnt_panic(input in "[\\[\\],0-9]{0,30}") {
+        let _ = parser(2021, 18)(&input);
+    }
+
+    #[test]
+    fn alu_parser_doesnt_panic(input in alu_program()) {
+        let _ = parser(2021, 24)(&input);
+    }
+}
+
+#[test]
+fn rejects_inputs_the_solutions_cant_index_into() {
+    assert!(parser(2021, 3)("1\n101").is_err());
+    assert!(parser(2021, 3)("").is_err());
+    assert!(parser(2021, 3)(&"1".repeat(70)).is_err());
+    assert!(parser(2021, 8)("ab | ab").is_err());
+    assert!(parser(2021, 8)("ab abc abcd abcde bcdef cdefg abcdef bcdefg cdefga abcdefg | ab").is_err());
+    assert!(parser(2021, 8)("ab ab ab ab ab ab ab ab ab ab | ab ab ab ab").is_err());
+    assert!(parser(2021, 8)("ab abc abcd abcde bcdef cdefg abcdef bcdefg cdefga abcdefg | ab abc abcd abcdefg").is_ok());
+}
+
+#[test]
+fn every_parser_rejects_garbage() {
+    for (name, parse) in parsers() {
+        assert!(parse("\u{1F419}!").is_err(), "{} accepted garbage", name);
+    }
+}